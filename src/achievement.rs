@@ -0,0 +1,54 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use turbo::borsh;
+
+use crate::{AdventureEvent, Goblin, Rarity};
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Achievement {
+    FirstEpic,
+    OnARoll,
+    NotGreedy,
+    HangingOn,
+}
+impl Achievement {
+    // Events in a row without a break for `OnARoll`
+    pub const STREAK_LEN: u8 = 10;
+    pub const ALL: &'static [Self] = &[
+        Self::FirstEpic,
+        Self::OnARoll,
+        Self::NotGreedy,
+        Self::HangingOn,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FirstEpic => "Shiny!",
+            Self::OnARoll => "On a Roll",
+            Self::NotGreedy => "Who, Me?",
+            Self::HangingOn => "Hanging On",
+        }
+    }
+    pub fn desc(&self) -> &'static str {
+        match self {
+            Self::FirstEpic => "Find a piece of epic loot.",
+            Self::OnARoll => "Survive 10 events without a break.",
+            Self::NotGreedy => "Win an adventure with zero greed.",
+            Self::HangingOn => "Finish an adventure with 1 health.",
+        }
+    }
+    /// Whether an event earns a goblin the achievement.
+    pub fn is_earned(&self, event: &AdventureEvent, goblin: &Goblin) -> bool {
+        match (self, event) {
+            (Self::FirstEpic, AdventureEvent::LootFound { rarity, .. }) => *rarity == Rarity::Epic,
+            (Self::OnARoll, AdventureEvent::EventSurvived { streak, .. }) => {
+                *streak >= Self::STREAK_LEN
+            }
+            (Self::NotGreedy, AdventureEvent::Completed { won, .. }) => *won && goblin.greed == 0,
+            (Self::HangingOn, AdventureEvent::Completed { .. }) => {
+                !goblin.is_fired && goblin.health == 1
+            }
+            _ => false,
+        }
+    }
+}
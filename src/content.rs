@@ -0,0 +1,625 @@
+use serde::de::{self, DeserializeOwned, Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use super::*;
+
+////////////////////////////////////////////////////////////////////////////////
+// Content Pack
+////////////////////////////////////////////////////////////////////////////////
+//
+// Locations, scenarios, outcomes and goblin dialog live in TOML files under
+// `content/`. `content/pack.toml` lists the other files, and every file is
+// embedded in the binary so the game never has to touch the file system.
+// Files are decoded straight into the content structs with `serde`, checking
+// dialog scripts and references to game data on the way so errors point at
+// the value that caused them.
+
+pub const CONTENT_MANIFEST: &'static str = "pack.toml";
+
+pub const EMBEDDED_CONTENT: &[(&'static str, &'static str)] = &[
+    ("pack.toml", include_str!("../content/pack.toml")),
+    ("camp.toml", include_str!("../content/camp.toml")),
+    ("dialog.toml", include_str!("../content/dialog.toml")),
+    ("outcomes.toml", include_str!("../content/outcomes.toml")),
+    ("locations/forgotten_ruins.toml", include_str!("../content/locations/forgotten_ruins.toml")),
+    ("locations/whispering_woods.toml", include_str!("../content/locations/whispering_woods.toml")),
+    ("locations/sunken_city.toml", include_str!("../content/locations/sunken_city.toml")),
+    ("locations/deserted_village.toml", include_str!("../content/locations/deserted_village.toml")),
+    ("locations/frozen_wasteland.toml", include_str!("../content/locations/frozen_wasteland.toml")),
+    ("locations/volcanic_ridge.toml", include_str!("../content/locations/volcanic_ridge.toml")),
+    ("locations/grand_hall.toml", include_str!("../content/locations/grand_hall.toml")),
+    ("locations/crystal_cavern.toml", include_str!("../content/locations/crystal_cavern.toml")),
+    ("locations/mysterious_cave.toml", include_str!("../content/locations/mysterious_cave.toml")),
+    ("locations/luminous_passageway.toml", include_str!("../content/locations/luminous_passageway.toml")),
+    ("locations/haunted_cave.toml", include_str!("../content/locations/haunted_cave.toml")),
+    ("locations/ominous_corridor.toml", include_str!("../content/locations/ominous_corridor.toml")),
+    ("locations/lush_cavern.toml", include_str!("../content/locations/lush_cavern.toml")),
+    ("locations/throne_room.toml", include_str!("../content/locations/throne_room.toml")),
+    ("locations/cursed_vault.toml", include_str!("../content/locations/cursed_vault.toml")),
+];
+
+thread_local! {
+    static CONTENT: RefCell<Rc<ContentPack>> = RefCell::new(Rc::new(
+        ContentPack::embedded().unwrap_or_else(|err| {
+            turbo::println!("Invalid embedded content: {}", err);
+            ContentPack::fallback()
+        }),
+    ));
+}
+
+/// The content pack the game is currently using.
+pub fn content() -> Rc<ContentPack> {
+    CONTENT.with(|content| content.borrow().clone())
+}
+
+/// Swaps in a new content pack, returning the one it replaced.
+pub fn set_content(pack: ContentPack) -> Rc<ContentPack> {
+    CONTENT.with(|content| content.replace(Rc::new(pack)))
+}
+
+/// Loads a pack and swaps it in if it differs from the current one, returning
+/// the pack it replaced. An invalid pack leaves the current one in place.
+pub fn reload_content(
+    read: impl Fn(&str) -> Option<String>,
+) -> Result<Option<Rc<ContentPack>>, ContentError> {
+    let pack = ContentPack::load(read)?;
+    if pack == *content() {
+        return Ok(None);
+    }
+    Ok(Some(set_content(pack)))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Hot Reload
+////////////////////////////////////////////////////////////////////////////////
+//
+// Development builds with the `hot-reload` feature read the pack straight from
+// `content/` on disk and poll it for changes. When a file changes the whole
+// pack is reloaded and validated. A valid pack replaces the active one; an
+// invalid one is ignored and its error is kept for the on-screen overlay until
+// the files are fixed.
+
+#[cfg(feature = "hot-reload")]
+pub const CONTENT_DIR: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/content");
+
+#[cfg(feature = "hot-reload")]
+pub const CONTENT_POLL_INTERVAL: u32 = 30;
+
+#[cfg(feature = "hot-reload")]
+#[derive(Debug, Default)]
+struct ContentWatcher {
+    frame: u32,
+    // Every file the last load asked for, `None` if it couldn't be read
+    files: HashMap<String, Option<String>>,
+    error: Option<String>,
+}
+
+#[cfg(feature = "hot-reload")]
+thread_local! {
+    static CONTENT_WATCHER: RefCell<ContentWatcher> = RefCell::new(ContentWatcher::default());
+}
+
+#[cfg(feature = "hot-reload")]
+fn read_content_file(path: &str) -> Option<String> {
+    std::fs::read_to_string(format!("{}/{}", CONTENT_DIR, path)).ok()
+}
+
+/// Call once per frame. Returns the previous pack if a new one was swapped in
+/// so in-progress state can be remapped onto it.
+#[cfg(feature = "hot-reload")]
+pub fn poll_content_files() -> Option<Rc<ContentPack>> {
+    CONTENT_WATCHER.with(|watcher| {
+        let mut watcher = watcher.borrow_mut();
+        watcher.frame = watcher.frame.wrapping_add(1);
+        if watcher.frame % CONTENT_POLL_INTERVAL != 1 {
+            return None;
+        }
+        let is_first_poll = watcher.files.is_empty();
+        let changed = watcher
+            .files
+            .iter()
+            .any(|(path, src)| read_content_file(path) != *src);
+        if !is_first_poll && !changed {
+            return None;
+        }
+        let files = RefCell::new(HashMap::new());
+        let result = reload_content(|path| {
+            let src = read_content_file(path);
+            files.borrow_mut().insert(path.to_string(), src.clone());
+            src
+        });
+        watcher.files = files.into_inner();
+        match result {
+            Ok(old) => {
+                watcher.error = None;
+                if old.is_some() {
+                    turbo::println!("Reloaded content from {}", CONTENT_DIR);
+                }
+                old
+            }
+            Err(err) => {
+                let message = if watcher.files.values().all(|src| src.is_none()) {
+                    format!("Can't read {}. Hot reload needs file system access.", CONTENT_DIR)
+                } else {
+                    err.to_string()
+                };
+                turbo::println!("Content reload failed: {}", message);
+                watcher.error = Some(message);
+                None
+            }
+        }
+    })
+}
+
+/// The validation error from the last reload attempt, if it failed.
+#[cfg(feature = "hot-reload")]
+pub fn content_reload_error() -> Option<String> {
+    CONTENT_WATCHER.with(|watcher| watcher.borrow().error.clone())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentPack {
+    pub camp: EventLocationData,
+    pub locations: Vec<EventLocationData>,
+    pub dialog: HashMap<DialogKey, Vec<String>>,
+}
+impl ContentPack {
+    /// The pack built into the game. The tests load it as well, so a broken
+    /// pack fails `cargo test` before it can ship.
+    pub fn embedded() -> Result<Self, ContentError> {
+        Self::load(|path| {
+            EMBEDDED_CONTENT
+                .iter()
+                .find(|(p, _)| *p == path)
+                .map(|(_, src)| src.to_string())
+        })
+    }
+    /// A bare bones pack to limp along with if the embedded one is broken.
+    pub fn fallback() -> Self {
+        let dialog = || vec![UNREACHABLE_DIALOG.to_string()];
+        let action = |label: &str| EventScenarioAction {
+            dialog: dialog(),
+            label: label.to_string(),
+            condition: None,
+            item: None,
+            outcomes: vec![EventScenarioOutcome {
+                weight: 1,
+                description: "Nothing happens.".to_string(),
+                dialog: dialog(),
+                effect: EventResult::OK,
+            }],
+        };
+        let location = |name: &str, scenarios| EventLocationData {
+            name: name.to_string(),
+            images: vec!["camp".to_string()],
+            dialog: dialog(),
+            description: "...".to_string(),
+            tier: 1,
+            scenarios,
+        };
+        let scenario = EventScenario {
+            name: "Nothing".to_string(),
+            description: "...".to_string(),
+            actions: vec![action("> Wait"), action("> Keep waiting")],
+        };
+        Self {
+            camp: location("Camp", vec![]),
+            locations: vec![location("Nowhere", vec![scenario])],
+            dialog: DialogKey::ALL.iter().map(|key| (*key, dialog())).collect(),
+        }
+    }
+    /// Loads and validates a whole pack. `read` returns the contents of a file
+    /// given its path relative to the manifest.
+    pub fn load(read: impl Fn(&str) -> Option<String>) -> Result<Self, ContentError> {
+        let manifest: ContentManifest = read_toml(&read, CONTENT_MANIFEST)?;
+        let outcome_tables = read_toml(&read, &manifest.outcomes)?;
+
+        // Dialog
+        let dialog: HashMap<DialogKey, DialogScripts> = read_toml(&read, &manifest.dialog)?;
+        let dialog: HashMap<_, _> = dialog.into_iter().map(|(key, d)| (key, d.0)).collect();
+        for key in DialogKey::ALL {
+            if !dialog.contains_key(key) {
+                let message = format!("missing dialog `{}`", key.name());
+                return Err(ContentError::new(&manifest.dialog, 1, 1, message));
+            }
+        }
+
+        // Locations, which can name the shared outcome tables
+        OUTCOME_TABLES.with(|tables| tables.replace(outcome_tables));
+        let locations = (|| {
+            let camp: EventLocationData = read_toml(&read, &manifest.camp)?;
+            let mut locations = vec![];
+            for path in &manifest.locations {
+                let location: EventLocationData = read_toml(&read, path)?;
+                if location.scenarios.is_empty() {
+                    let message = "location has no scenarios".to_string();
+                    return Err(ContentError::new(path, 1, 1, message));
+                }
+                locations.push(location);
+            }
+            Ok((camp, locations))
+        })();
+        OUTCOME_TABLES.with(|tables| tables.take());
+        let (camp, locations) = locations?;
+        if locations.is_empty() {
+            let message = "the pack has no locations".to_string();
+            return Err(ContentError::new(CONTENT_MANIFEST, 1, 1, message));
+        }
+
+        Ok(Self {
+            camp,
+            locations,
+            dialog,
+        })
+    }
+    pub fn location(&self, i: usize) -> &EventLocationData {
+        &self.locations[i % self.locations.len()]
+    }
+    pub fn dialog(&self, key: DialogKey) -> &[String] {
+        self.dialog.get(&key).map_or(&[], |d| d.as_slice())
+    }
+    pub fn random_dialog(&self, key: DialogKey) -> &str {
+        let dialog = self.dialog(key);
+        if dialog.is_empty() {
+            return UNREACHABLE_DIALOG;
+        }
+        &dialog[turbo::sys::rand() as usize % dialog.len()]
+    }
+}
+
+/// Dialog that isn't tied to a location, keyed by its name in `dialog.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DialogKey {
+    Unimplemented,
+    EnteringCamp,
+    LootRummage,
+    LootRummageFailAccept,
+    LootRummageTakeLoot,
+    LootRummageLeaveLoot,
+    KeepGoing,
+    TakeABreak,
+    GreedyFit,
+}
+impl DialogKey {
+    pub const ALL: &'static [Self] = &[
+        Self::Unimplemented,
+        Self::EnteringCamp,
+        Self::LootRummage,
+        Self::LootRummageFailAccept,
+        Self::LootRummageTakeLoot,
+        Self::LootRummageLeaveLoot,
+        Self::KeepGoing,
+        Self::TakeABreak,
+        Self::GreedyFit,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unimplemented => "unimplemented",
+            Self::EnteringCamp => "entering_camp",
+            Self::LootRummage => "loot_rummage",
+            Self::LootRummageFailAccept => "loot_rummage_fail_accept",
+            Self::LootRummageTakeLoot => "loot_rummage_take_loot",
+            Self::LootRummageLeaveLoot => "loot_rummage_leave_loot",
+            Self::KeepGoing => "keep_going",
+            Self::TakeABreak => "take_a_break",
+            Self::GreedyFit => "greedy_fit",
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Decoding
+////////////////////////////////////////////////////////////////////////////////
+
+/// `pack.toml`, listing the files that make up the pack.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContentManifest {
+    camp: String,
+    dialog: String,
+    outcomes: String,
+    locations: Vec<String>,
+}
+
+/// One entry of `dialog.toml`.
+#[derive(Deserialize)]
+struct DialogScripts(#[serde(deserialize_with = "deserialize_scripts")] Vec<String>);
+
+thread_local! {
+    // Shared outcome tables of the pack being loaded, see `deserialize_outcomes`
+    static OUTCOME_TABLES: RefCell<HashMap<String, Vec<EventScenarioOutcome>>> =
+        RefCell::new(HashMap::new());
+}
+
+fn read_toml<T: DeserializeOwned>(
+    read: &impl Fn(&str) -> Option<String>,
+    path: &str,
+) -> Result<T, ContentError> {
+    let src = read(path).ok_or_else(|| ContentError::missing_file(path))?;
+    toml::from_str(&src).map_err(|err| ContentError::from_toml(path, &src, &err))
+}
+
+/// Reads a list of dialog scripts, checking that each one parses.
+pub fn deserialize_scripts<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(try_from = "String")]
+    struct Script(String);
+    impl TryFrom<String> for Script {
+        type Error = String;
+        fn try_from(src: String) -> Result<Self, String> {
+            match DialogScript::parse(&src) {
+                Ok(_) => Ok(Self(src)),
+                Err(err) => {
+                    let DialogScriptError { line, message } = err;
+                    Err(format!("{} on line {} of the script", message, line))
+                }
+            }
+        }
+    }
+    let scripts = Vec::<Script>::deserialize(deserializer)?;
+    if scripts.is_empty() {
+        return Err(de::Error::custom("expected at least one line of dialog"));
+    }
+    Ok(scripts.into_iter().map(|script| script.0).collect())
+}
+
+pub fn deserialize_images<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let images = Vec::<String>::deserialize(deserializer)?;
+    if images.is_empty() {
+        return Err(de::Error::custom("`images` is empty"));
+    }
+    Ok(images)
+}
+
+pub fn deserialize_tier<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u8, D::Error> {
+    let tier = i64::deserialize(deserializer)?;
+    if tier < 1 || tier > EventLocationData::MAX_TIER as i64 {
+        let message = format!("`tier` must be from 1 to {}", EventLocationData::MAX_TIER);
+        return Err(de::Error::custom(message));
+    }
+    Ok(tier as u8)
+}
+
+pub fn deserialize_weight<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let weight = i64::deserialize(deserializer)?;
+    if weight <= 0 || weight > u32::MAX as i64 {
+        return Err(de::Error::custom("`weight` must be positive"));
+    }
+    Ok(weight as u32)
+}
+
+pub fn deserialize_actions<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<EventScenarioAction>, D::Error> {
+    let actions = Vec::<EventScenarioAction>::deserialize(deserializer)?;
+    if actions.len() < 2 {
+        return Err(de::Error::custom("scenarios need at least two actions"));
+    }
+    if actions.iter().all(|a| a.condition.is_some() || a.item.is_some()) {
+        let message = "scenarios need an action without requirements";
+        return Err(de::Error::custom(message));
+    }
+    Ok(actions)
+}
+
+/// Reads an action's outcomes, either listed in place or the name of one of
+/// the tables in `outcomes.toml`.
+pub fn deserialize_outcomes<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<EventScenarioOutcome>, D::Error> {
+    struct OutcomesVisitor;
+    impl<'de> Visitor<'de> for OutcomesVisitor {
+        type Value = Vec<EventScenarioOutcome>;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a list of outcomes or the name of an outcome table")
+        }
+        fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+            let outcomes = OUTCOME_TABLES.with(|tables| tables.borrow().get(name).cloned());
+            outcomes.ok_or_else(|| E::custom(format!("unknown outcome table `{}`", name)))
+        }
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            Vec::deserialize(de::value::SeqAccessDeserializer::new(seq))
+        }
+    }
+    let outcomes = deserializer.deserialize_any(OutcomesVisitor)?;
+    if outcomes.is_empty() {
+        return Err(de::Error::custom("`outcomes` is empty"));
+    }
+    Ok(outcomes)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentError {
+    pub file: String,
+    pub line: usize,
+    pub col: usize,
+    pub message: String,
+}
+impl ContentError {
+    pub fn new(file: &str, line: usize, col: usize, message: String) -> Self {
+        Self {
+            file: file.to_string(),
+            line,
+            col,
+            message,
+        }
+    }
+    fn missing_file(file: &str) -> Self {
+        Self::new(file, 0, 0, "file not found".to_string())
+    }
+    fn from_toml(file: &str, src: &str, err: &toml::de::Error) -> Self {
+        let start = err.span().map_or(0, |span| span.start);
+        let before = src.get(..start).unwrap_or(src);
+        let line = before.matches('\n').count() + 1;
+        let col = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+        Self::new(file, line, col, err.message().trim_end().to_string())
+    }
+}
+impl fmt::Display for ContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}: {}", self.file, self.message);
+        }
+        write!(f, "{}:{}:{}: {}", self.file, self.line, self.col, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_MANIFEST: &str = r#"camp = "camp.toml"
+dialog = "dialog.toml"
+outcomes = "outcomes.toml"
+locations = ["cave.toml"]
+"#;
+
+    const TEST_OUTCOMES: &str = r#"safe = [
+    { effect = "OK", weight = 1, description = "Fine.", dialog = ["Phew."] },
+]
+"#;
+
+    const TEST_CAMP: &str = r#"name = "Camp"
+images = ["camp"]
+description = "Snoring heroes."
+dialog = ["Shh."]
+"#;
+
+    const TEST_CAVE: &str = r#"name = "Cave"
+images = ["cave"]
+description = "Damp."
+dialog = ["""
+Drip.
+* Listen -> end"""]
+tier = 2
+
+[[scenarios]]
+name = "Puddle"
+description = "A puddle."
+
+[[scenarios.actions]]
+label = "Jump"
+dialog = ["Wheee!"]
+outcomes = "safe"
+
+[[scenarios.actions]]
+label = "Drink"
+dialog = ["Gulp."]
+condition = "health < 2"
+outcomes = [{ effect = "Heal", weight = 3, description = "Yum.", dialog = ["Ahh."] }]
+"#;
+
+    /// A small pack that uses a bit of everything.
+    fn test_files() -> HashMap<&'static str, String> {
+        let dialog = DialogKey::ALL
+            .iter()
+            .map(|key| format!("{} = [\"Psst, {{goblin}}!\"]\n", key.name()))
+            .collect::<String>();
+        HashMap::from([
+            (CONTENT_MANIFEST, TEST_MANIFEST.to_string()),
+            ("outcomes.toml", TEST_OUTCOMES.to_string()),
+            ("dialog.toml", dialog),
+            ("camp.toml", TEST_CAMP.to_string()),
+            ("cave.toml", TEST_CAVE.to_string()),
+        ])
+    }
+
+    fn load(files: &HashMap<&'static str, String>) -> Result<ContentPack, ContentError> {
+        ContentPack::load(|path| files.get(path).cloned())
+    }
+
+    /// Loads the test pack with one edit to one of its files.
+    fn load_patched(path: &str, from: &str, to: &str) -> ContentError {
+        let mut files = test_files();
+        let src = files.get_mut(path).unwrap();
+        assert!(src.contains(from), "{} doesn't contain {}", path, from);
+        *src = src.replacen(from, to, 1);
+        load(&files).unwrap_err()
+    }
+
+    #[test]
+    fn embedded_pack_is_valid() {
+        let pack = ContentPack::embedded().unwrap_or_else(|err| panic!("{}", err));
+        assert_eq!(pack.locations.len(), EMBEDDED_CONTENT.len() - 4);
+    }
+
+    #[test]
+    fn fallback_pack_is_playable() {
+        let pack = ContentPack::fallback();
+        assert!(DialogKey::ALL.iter().all(|k| !pack.dialog(*k).is_empty()));
+        let scenarios = &pack.location(0).scenarios;
+        assert!(!scenarios.is_empty() && scenarios.iter().all(|s| s.actions.len() >= 2));
+    }
+
+    #[test]
+    fn load_pack() {
+        let pack = load(&test_files()).unwrap_or_else(|err| panic!("{}", err));
+        assert_eq!((pack.camp.name.as_str(), pack.camp.tier), ("Camp", 1));
+        assert!(pack.camp.scenarios.is_empty());
+        let cave = pack.location(0);
+        assert_eq!((cave.name.as_str(), cave.tier), ("Cave", 2));
+        assert_eq!(cave.dialog, ["Drip.\n* Listen -> end"]);
+        let actions = &cave.scenarios[0].actions;
+        assert_eq!(actions[0].outcomes[0].effect, EventResult::OK);
+        assert_eq!(actions[1].outcomes[0].weight, 3);
+        assert!(actions[1].condition.is_some());
+        assert_eq!(pack.dialog(DialogKey::GreedyFit), ["Psst, {goblin}!"]);
+    }
+
+    #[test]
+    fn load_errors() {
+        #[rustfmt::skip]
+        let cases = [
+            (load_patched(CONTENT_MANIFEST, "camp.toml", "tent.toml"), "tent.toml", 0, 0, "file not found"),
+            (load_patched(CONTENT_MANIFEST, "camp = ", "tent = "), CONTENT_MANIFEST, 1, 1, "unknown field `tent`"),
+            (load_patched("camp.toml", "description = \"Snoring heroes.\"\n", ""), "camp.toml", 1, 1, "missing field `description`"),
+            (load_patched("cave.toml", "tier = 2", "tier = 9"), "cave.toml", 7, 8, "`tier` must be from 1 to 3"),
+            (load_patched("cave.toml", "tier = 2", "tier = 2\ncolour = 1"), "cave.toml", 8, 1, "unknown field `colour`"),
+            (load_patched("cave.toml", "\"safe\"", "\"risky\""), "cave.toml", 16, 12, "unknown outcome table `risky`"),
+            (load_patched("cave.toml", "weight = 3", "weight = 0"), "cave.toml", 22, 41, "`weight` must be positive"),
+            (load_patched("cave.toml", "\"Heal\"", "\"Nap\""), "cave.toml", 22, 24, "unknown variant `Nap`"),
+            (load_patched("cave.toml", "health < 2", "hunger < 2"), "cave.toml", 21, 13, "unknown stat `hunger`"),
+            (load_patched("cave.toml", "\"Jump\"", "\"Jump\"\nitem = \"rope\""), "cave.toml", 15, 8, "unknown variant `rope`"),
+            (load_patched("outcomes.toml", "weight = 1", "weight = 'heavy'"), "outcomes.toml", 2, 31, "invalid type: string \"heavy\""),
+            (load_patched("dialog.toml", "unimplemented", "unimplemneted"), "dialog.toml", 1, 1, "unknown variant `unimplemneted`"),
+            (load_patched("dialog.toml", "[\"Psst, {goblin}!\"]", "[]"), "dialog.toml", 1, 17, "expected at least one line of dialog"),
+            (load_patched("dialog.toml", "Psst", "{gobbo}"), "dialog.toml", 1, 17, "unknown variable `{gobbo}` on line 1 of the script"),
+            (load_patched("cave.toml", "-> end", "-> nowhere"), "cave.toml", 4, 10, "unknown label `nowhere` on line 2 of the script"),
+        ];
+        for (err, file, line, col, message) in cases {
+            // Serde goes on to list what it expected, which doesn't need pinning down
+            let position = (err.file.as_str(), err.line, err.col);
+            assert_eq!(position, (file, line, col), "{}", err);
+            assert!(err.message.starts_with(message), "{}", err);
+        }
+    }
+
+    #[test]
+    fn reload_keeps_last_good_pack() {
+        let mut files = test_files();
+        let old = reload_content(|path| files.get(path).cloned()).unwrap();
+        assert!(old.is_some());
+        let good = content();
+        // Nothing changed, so nothing gets swapped
+        let old = reload_content(|path| files.get(path).cloned()).unwrap();
+        assert!(old.is_none());
+        let cave = files.get_mut("cave.toml").unwrap();
+        *cave = cave.replacen("tier = 2", "tier = ", 1);
+        let err = reload_content(|path| files.get(path).cloned()).unwrap_err();
+        assert_eq!((err.file.as_str(), err.line, err.col), ("cave.toml", 7, 8));
+        assert!(Rc::ptr_eq(&good, &content()));
+    }
+}
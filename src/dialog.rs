@@ -0,0 +1,578 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Deserialize;
+use std::fmt;
+use turbo::borsh;
+
+use super::*;
+
+////////////////////////////////////////////////////////////////////////////////
+// Dialog Scripts
+////////////////////////////////////////////////////////////////////////////////
+//
+// Every piece of goblin dialog is a small script. A single line of text is
+// already a valid script, so most dialog is written exactly as before. The
+// format is line-based:
+//
+//   # comment                    ignored
+//   Plain text                   appended to the current node's message
+//   [greed >= 3] Plain text      only shown when the condition holds
+//   * [luck > 0] Choice -> label a choice that jumps to `label` (or `end`)
+//   -> label                     continues to `label` on the next page
+//   == label                     starts a new node
+//
+// Text may contain the variables listed in `DialogScript::VARIABLES`, and
+// conditions compare one of `DialogStat::ALL` against a number.
+
+pub const DEFAULT_GOBLIN_NAME: &'static str = "Snitch";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogScript {
+    pub nodes: Vec<DialogNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogNode {
+    pub label: String,
+    pub lines: Vec<DialogLine>,
+    pub choices: Vec<DialogChoice>,
+    pub next: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogLine {
+    pub condition: Option<DialogCondition>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogChoice {
+    pub condition: Option<DialogCondition>,
+    pub text: String,
+    pub target: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct DialogCondition {
+    pub stat: DialogStat,
+    pub op: DialogOp,
+    pub value: u32,
+}
+impl TryFrom<String> for DialogCondition {
+    type Error = String;
+    fn try_from(input: String) -> Result<Self, String> {
+        Self::parse(&input)
+    }
+}
+impl DialogCondition {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        let stat_len = input
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(input.len());
+        let (stat, rest) = input.split_at(stat_len);
+        let stat = DialogStat::ALL
+            .iter()
+            .find(|s| s.name() == stat)
+            .copied()
+            .ok_or_else(|| format!("unknown stat `{}`", stat))?;
+        let rest = rest.trim_start();
+        let op_len = rest
+            .find(|c: char| !"=!<>".contains(c))
+            .unwrap_or(rest.len());
+        let (op, value) = rest.split_at(op_len);
+        let op = match op {
+            "=" | "==" => DialogOp::Eq,
+            "!=" => DialogOp::Ne,
+            "<" => DialogOp::Lt,
+            "<=" => DialogOp::Le,
+            ">" => DialogOp::Gt,
+            ">=" => DialogOp::Ge,
+            _ => return Err(format!("unknown operator `{}`", op)),
+        };
+        let value = value.trim();
+        let value = value
+            .parse::<u32>()
+            .map_err(|_| format!("expected a number, found `{}`", value))?;
+        Ok(Self { stat, op, value })
+    }
+    pub fn eval(&self, ctx: &DialogContext) -> bool {
+        let stat = ctx.stat(self.stat);
+        match self.op {
+            DialogOp::Eq => stat == self.value,
+            DialogOp::Ne => stat != self.value,
+            DialogOp::Lt => stat < self.value,
+            DialogOp::Le => stat <= self.value,
+            DialogOp::Gt => stat > self.value,
+            DialogOp::Ge => stat >= self.value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogStat {
+    Health,
+    Luck,
+    Greed,
+    Loot,
+}
+impl DialogStat {
+    pub const ALL: &'static [Self] = &[Self::Health, Self::Luck, Self::Greed, Self::Loot];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Health => "health",
+            Self::Luck => "luck",
+            Self::Greed => "greed",
+            Self::Loot => "loot",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogScriptError {
+    pub line: usize,
+    pub message: String,
+}
+impl fmt::Display for DialogScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Everything a script can read while being rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogContext<'a> {
+    pub goblin: &'a str,
+    pub location: &'a str,
+    pub health: u8,
+    pub luck: u8,
+    pub greed: u8,
+    pub loot: usize,
+}
+impl<'a> DialogContext<'a> {
+    pub fn new(goblin: &'a Goblin, location: &'a str) -> Self {
+        Self {
+            goblin: if goblin.name.is_empty() {
+                DEFAULT_GOBLIN_NAME
+            } else {
+                &goblin.name
+            },
+            location,
+            health: goblin.health,
+            luck: goblin.luck,
+            greed: goblin.greed,
+            loot: goblin.loot.len(),
+        }
+    }
+    pub fn stat(&self, stat: DialogStat) -> u32 {
+        match stat {
+            DialogStat::Health => self.health as u32,
+            DialogStat::Luck => self.luck as u32,
+            DialogStat::Greed => self.greed as u32,
+            DialogStat::Loot => self.loot as u32,
+        }
+    }
+    pub fn var(&self, name: &str) -> Option<String> {
+        match name {
+            "goblin" => Some(self.goblin.to_string()),
+            "location" => Some(self.location.to_string()),
+            "loot" => Some(self.loot.to_string()),
+            "health" => Some(self.health.to_string()),
+            "luck" => Some(self.luck.to_string()),
+            "greed" => Some(self.greed.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// A rendered node of a script, ready to be shown by `GoblinDialog`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DialogPage {
+    pub message: String,
+    pub choices: Vec<DialogPageChoice>,
+    pub next: Option<usize>,
+}
+impl DialogPage {
+    pub fn text(message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            choices: vec![],
+            next: None,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DialogPageChoice {
+    pub text: String,
+    pub target: Option<usize>,
+}
+
+impl DialogScript {
+    pub const START: &'static str = "start";
+    pub const END: &'static str = "end";
+    pub const VARIABLES: &'static [&'static str] =
+        &["goblin", "location", "loot", "health", "luck", "greed"];
+
+    pub fn parse(src: &str) -> Result<Self, DialogScriptError> {
+        let err = |line: usize, message: String| DialogScriptError { line, message };
+        let mut nodes = vec![DialogNode::new(Self::START)];
+        let mut node_lines = vec![1];
+        let mut is_implicit_start = true;
+        // (line, node, choice, label) for every `->`, resolved once all labels are known
+        let mut jumps: Vec<(usize, usize, Option<usize>, String)> = vec![];
+        for (i, line) in src.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let node = nodes.len() - 1;
+            if let Some(label) = line.strip_prefix("==") {
+                let label = parse_label(label).map_err(|e| err(line_no, e))?;
+                if is_implicit_start && nodes[0].is_empty() && jumps.is_empty() {
+                    // The implicit start node was never used, so this becomes the start
+                    is_implicit_start = false;
+                    nodes[0].label = label;
+                    node_lines[0] = line_no;
+                    continue;
+                }
+                is_implicit_start = false;
+                if nodes.iter().any(|n| n.label == label) {
+                    return Err(err(line_no, format!("duplicate label `{}`", label)));
+                }
+                nodes.push(DialogNode::new(&label));
+                node_lines.push(line_no);
+            } else if let Some(label) = line.strip_prefix("->") {
+                if jumps.iter().any(|(_, n, c, _)| *n == node && c.is_none()) {
+                    let message = format!("node `{}` already continues", nodes[node].label);
+                    return Err(err(line_no, message));
+                }
+                let label = parse_label(label).map_err(|e| err(line_no, e))?;
+                jumps.push((line_no, node, None, label));
+            } else if let Some(choice) = line.strip_prefix('*') {
+                let (condition, choice) =
+                    parse_condition_prefix(choice.trim()).map_err(|e| err(line_no, e))?;
+                let (text, label) = choice
+                    .rsplit_once("->")
+                    .ok_or_else(|| err(line_no, "choice is missing `-> label`".to_string()))?;
+                let text = parse_text(text).map_err(|e| err(line_no, e))?;
+                let label = parse_label(label).map_err(|e| err(line_no, e))?;
+                jumps.push((line_no, node, Some(nodes[node].choices.len()), label));
+                nodes[node].choices.push(DialogChoice {
+                    condition,
+                    text,
+                    target: None,
+                });
+            } else {
+                let (condition, text) =
+                    parse_condition_prefix(line).map_err(|e| err(line_no, e))?;
+                let text = parse_text(text).map_err(|e| err(line_no, e))?;
+                nodes[node].lines.push(DialogLine { condition, text });
+            }
+        }
+        for (line_no, node, choice, label) in jumps {
+            let target = if label == Self::END {
+                None
+            } else {
+                let target = nodes.iter().position(|n| n.label == label);
+                Some(target.ok_or_else(|| err(line_no, format!("unknown label `{}`", label)))?)
+            };
+            match choice {
+                Some(choice) => nodes[node].choices[choice].target = target,
+                None => {
+                    if !nodes[node].choices.is_empty() {
+                        let message =
+                            format!("node `{}` has both choices and `->`", nodes[node].label);
+                        return Err(err(line_no, message));
+                    }
+                    nodes[node].next = target;
+                }
+            }
+        }
+        for (node, line_no) in nodes.iter().zip(node_lines) {
+            if node.is_empty() {
+                return Err(err(line_no, format!("node `{}` is empty", node.label)));
+            }
+        }
+        Ok(Self { nodes })
+    }
+    pub fn page(&self, node: usize, ctx: &DialogContext) -> DialogPage {
+        let node = &self.nodes[node.min(self.nodes.len() - 1)];
+        let is_visible = |c: &Option<DialogCondition>| c.map_or(true, |c| c.eval(ctx));
+        let message = node
+            .lines
+            .iter()
+            .filter(|line| is_visible(&line.condition))
+            .map(|line| interpolate(&line.text, ctx))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let choices = node
+            .choices
+            .iter()
+            .filter(|choice| is_visible(&choice.condition))
+            .map(|choice| DialogPageChoice {
+                text: interpolate(&choice.text, ctx),
+                target: choice.target,
+            })
+            .collect();
+        DialogPage {
+            message,
+            choices,
+            next: node.next,
+        }
+    }
+}
+
+impl DialogNode {
+    fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            lines: vec![],
+            choices: vec![],
+            next: None,
+        }
+    }
+    fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.choices.is_empty()
+    }
+}
+
+fn parse_label(input: &str) -> Result<String, String> {
+    let label = input.trim();
+    if label.is_empty() {
+        return Err("expected a label".to_string());
+    }
+    if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!("invalid label `{}`", label));
+    }
+    Ok(label.to_string())
+}
+
+fn parse_condition_prefix(input: &str) -> Result<(Option<DialogCondition>, &str), String> {
+    if let Some(rest) = input.strip_prefix('[') {
+        let (condition, rest) = rest
+            .split_once(']')
+            .ok_or_else(|| "condition is missing `]`".to_string())?;
+        return Ok((Some(DialogCondition::parse(condition)?), rest.trim()));
+    }
+    Ok((None, input))
+}
+
+fn parse_text(input: &str) -> Result<String, String> {
+    let text = input.trim();
+    if text.is_empty() {
+        return Err("expected some text".to_string());
+    }
+    let mut rest = text;
+    while let Some(start) = rest.find(|c| c == '{' || c == '}') {
+        if rest[start..].starts_with('}') {
+            return Err("unexpected `}`".to_string());
+        }
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| "variable is missing `}`".to_string())?;
+        let name = &rest[start + 1..start + end];
+        if !DialogScript::VARIABLES.contains(&name) {
+            return Err(format!("unknown variable `{{{}}}`", name));
+        }
+        rest = &rest[start + end + 1..];
+    }
+    Ok(text.to_string())
+}
+
+fn interpolate(text: &str, ctx: &DialogContext) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let end = rest[start..].find('}').map_or(rest.len(), |i| start + i);
+        let name = &rest[(start + 1).min(end)..end];
+        result.push_str(&ctx.var(name).unwrap_or_default());
+        rest = &rest[(end + 1).min(rest.len())..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every dialog script in the embedded content pack, with the file it's in.
+    fn embedded_scripts() -> Vec<(&'static str, String)> {
+        fn collect(
+            file: &'static str,
+            key: &str,
+            value: &toml::Value,
+            scripts: &mut Vec<(&'static str, String)>,
+        ) {
+            match value {
+                toml::Value::String(script) if key == "dialog" => {
+                    scripts.push((file, script.clone()))
+                }
+                toml::Value::Array(items) => {
+                    for item in items {
+                        collect(file, key, item, scripts);
+                    }
+                }
+                toml::Value::Table(table) => {
+                    for (key, value) in table {
+                        collect(file, key, value, scripts);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut scripts = vec![];
+        for (file, src) in EMBEDDED_CONTENT {
+            let table: toml::Table = toml::from_str(src).unwrap_or_else(|err| panic!("{}", err));
+            for (key, value) in &table {
+                // Every key in the dialog file is a list of scripts
+                let key = if *file == "dialog.toml" {
+                    "dialog"
+                } else {
+                    key.as_str()
+                };
+                collect(file, key, value, &mut scripts);
+            }
+        }
+        scripts
+    }
+
+    #[test]
+    fn embedded_scripts_are_valid() {
+        let goblin = Goblin::new();
+        let ctx = DialogContext::new(&goblin, "Camp");
+        let scripts = embedded_scripts();
+        assert!(scripts.len() > 100);
+        for (file, src) in scripts {
+            let script = DialogScript::parse(&src)
+                .unwrap_or_else(|err| panic!("{}: {}\n{}", file, err, src));
+            for node in 0..script.nodes.len() {
+                let page = script.page(node, &ctx);
+                assert!(!page.message.contains('{'), "{}: {}", file, page.message);
+            }
+        }
+    }
+
+    #[test]
+    fn plain_text() {
+        let goblin = Goblin::new();
+        let ctx = DialogContext::new(&goblin, "Grand Hall");
+        let script = DialogScript::parse("Sneaky {goblin} in the {location}!").unwrap();
+        assert_eq!(script.nodes.len(), 1);
+        let page = script.page(0, &ctx);
+        let message = format!("Sneaky {} in the Grand Hall!", goblin.name);
+        assert_eq!(
+            page,
+            DialogPage {
+                message,
+                choices: vec![],
+                next: None
+            }
+        );
+    }
+
+    #[test]
+    fn choices_and_jumps() {
+        let src = "
+            # Picking a fight
+            Psst!
+            * Fight -> fight
+            * [luck > 0] Run -> end
+            == fight
+            Slap!
+            -> after
+            == after
+            Ow.
+        ";
+        let script = DialogScript::parse(src).unwrap();
+        let labels = script
+            .nodes
+            .iter()
+            .map(|n| n.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["start", "fight", "after"]);
+        let mut goblin = Goblin::new();
+        goblin.luck = 0;
+        let ctx = DialogContext::new(&goblin, "Camp");
+        let page = script.page(0, &ctx);
+        assert_eq!(page.message, "Psst!");
+        let choice = DialogPageChoice {
+            text: "Fight".to_string(),
+            target: Some(1),
+        };
+        assert_eq!(page.choices, [choice]);
+        goblin.luck = 1;
+        let ctx = DialogContext::new(&goblin, "Camp");
+        assert_eq!(script.page(0, &ctx).choices[1].target, None);
+        assert_eq!(script.page(1, &ctx).next, Some(2));
+    }
+
+    #[test]
+    fn conditions() {
+        let mut goblin = Goblin::new();
+        goblin.greed = 3;
+        let ctx = DialogContext::new(&goblin, "Camp");
+        let script = DialogScript::parse("[greed >= 3] Mine!\n[greed < 3] Yours.\nBye.").unwrap();
+        assert_eq!(script.page(0, &ctx).message, "Mine! Bye.");
+        for (src, is_true) in [
+            ("greed = 3", true),
+            ("greed != 3", false),
+            ("greed <= 2", false),
+            ("greed > 2", true),
+        ] {
+            assert_eq!(
+                DialogCondition::parse(src).unwrap().eval(&ctx),
+                is_true,
+                "{}",
+                src
+            );
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            ("{foo}", 1, "unknown variable `{foo}`"),
+            ("Hi {goblin", 1, "variable is missing `}`"),
+            ("Hi }", 1, "unexpected `}`"),
+            ("Hi\n* Go", 2, "choice is missing `-> label`"),
+            ("Hi\n\n-> nope", 3, "unknown label `nope`"),
+            ("Hi\n-> a b", 2, "invalid label `a b`"),
+            ("[wat > 1] Hi", 1, "unknown stat `wat`"),
+            ("[luck > 1 Hi", 1, "condition is missing `]`"),
+            ("== a\nHi\n== a\nHo", 3, "duplicate label `a`"),
+            ("== a\n== b\nHi", 1, "node `a` is empty"),
+            ("Hi\n-> end\n-> end", 3, "node `start` already continues"),
+            (
+                "Hi\n* Go -> end\n-> end",
+                3,
+                "node `start` has both choices and `->`",
+            ),
+        ];
+        for (src, line, message) in cases {
+            let err = DialogScript::parse(src).unwrap_err();
+            assert_eq!(
+                err,
+                DialogScriptError {
+                    line,
+                    message: message.to_string()
+                },
+                "{}",
+                src
+            );
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+use turbo::{borsh, solana::solana_sdk};
+
+use crate::{Adventure, AdventureState, Player};
+
+pub const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+pub const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Folds bytes into a 64-bit FNV-1a hash.
+pub fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+/// Top scores of finished adventures, one board per party size and round
+/// count, plus one per daily challenge.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+}
+impl Leaderboard {
+    pub const MAX_BOARD_LEN: usize = 10;
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+    /// Adds every goblin's score from a complete adventure, keeping only the
+    /// best scores on each board.
+    pub fn record(&mut self, adventure: &Adventure) -> Result<(), ()> {
+        if let AdventureState::Complete(goblins, settings, sack) = &adventure.state {
+            let party_size = goblins.len() as u8;
+            for (player, goblin) in goblins {
+                self.entries.push(LeaderboardEntry::new(
+                    adventure.creator,
+                    *player,
+                    goblin.name.clone(),
+                    party_size,
+                    settings.num_rounds,
+                    sack.score(goblin, goblins.len()),
+                    adventure.replay_hash,
+                    adventure.challenge,
+                ));
+            }
+            self.entries.sort_by(|a, b| b.score.cmp(&a.score));
+            let mut board_lens = std::collections::HashMap::new();
+            self.entries.retain(|entry| {
+                let board = (entry.party_size, entry.num_rounds, entry.challenge);
+                let len = board_lens.entry(board).or_insert(0);
+                *len += 1;
+                *len <= Self::MAX_BOARD_LEN
+            });
+            return Ok(());
+        }
+        return Err(());
+    }
+    /// The entries of one board, best first.
+    pub fn board(&self, party_size: u8, num_rounds: u8) -> Vec<&LeaderboardEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.challenge == None)
+            .filter(|entry| entry.party_size == party_size && entry.num_rounds == num_rounds)
+            .collect()
+    }
+    /// The entries of a day's challenge, best first.
+    pub fn daily_board(&self, day: u32) -> Vec<&LeaderboardEntry> {
+        self.entries.iter().filter(|entry| entry.challenge == Some(day)).collect()
+    }
+    /// Round counts that have a board for the party size, in order.
+    pub fn round_counts(&self, party_size: u8) -> Vec<u8> {
+        let mut round_counts = self
+            .entries
+            .iter()
+            .filter(|entry| entry.challenge == None && entry.party_size == party_size)
+            .map(|entry| entry.num_rounds)
+            .collect::<Vec<_>>();
+        round_counts.sort();
+        round_counts.dedup();
+        round_counts
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub creator: Pubkey,
+    pub player: Player,
+    pub name: String,
+    pub party_size: u8,
+    pub num_rounds: u8,
+    pub score: u32,
+    // Hash of every state the adventure went through, see `Adventure::replay_hash`
+    pub replay_hash: u64,
+    // The day of the daily challenge the score was set in
+    pub challenge: Option<u32>,
+    // Hash of all the fields above, tying the score to the run it came from
+    pub checksum: u64,
+}
+impl LeaderboardEntry {
+    pub fn new(
+        creator: Pubkey,
+        player: Player,
+        name: String,
+        party_size: u8,
+        num_rounds: u8,
+        score: u32,
+        replay_hash: u64,
+        challenge: Option<u32>,
+    ) -> Self {
+        let mut entry = Self {
+            creator,
+            player,
+            name,
+            party_size,
+            num_rounds,
+            score,
+            replay_hash,
+            challenge,
+            checksum: 0,
+        };
+        entry.checksum = entry.compute_checksum();
+        entry
+    }
+    /// Hashes the entry together with its run's replay hash. There's no secret
+    /// to key it with, so anyone with the code can forge a matching checksum.
+    /// It only catches saves that were edited by hand or got corrupted.
+    pub fn compute_checksum(&self) -> u64 {
+        let hash = fnv1a(FNV_OFFSET_BASIS, self.creator.as_ref());
+        let hash = fnv1a(hash, &[self.player.index() as u8]);
+        let hash = fnv1a(hash, self.name.as_bytes());
+        let hash = fnv1a(hash, &[self.party_size, self.num_rounds]);
+        let hash = fnv1a(hash, &self.score.to_le_bytes());
+        let hash = fnv1a(hash, &self.replay_hash.to_le_bytes());
+        fnv1a(hash, &self.challenge.map_or([0xff; 4], u32::to_le_bytes))
+    }
+    /// Whether the entry still matches its checksum.
+    pub fn is_valid(&self) -> bool {
+        self.checksum == self.compute_checksum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PartySack;
+
+    /// A leaderboard with the scores of a finished adventure.
+    fn leaderboard() -> Leaderboard {
+        let mut adventure = Adventure::new(Pubkey::default());
+        adventure.start_adventure().unwrap();
+        let AdventureState::Started(goblins, settings, ..) = adventure.state.clone() else {
+            panic!("the adventure didn't start");
+        };
+        adventure.state = AdventureState::Complete(goblins, settings, PartySack::new());
+        adventure.update_replay_hash();
+        let mut leaderboard = Leaderboard::new();
+        leaderboard.record(&adventure).unwrap();
+        leaderboard
+    }
+
+    #[test]
+    fn edited_entries_are_invalid() {
+        let entry = leaderboard().entries.remove(0);
+        assert!(entry.is_valid());
+        let edits: [fn(&mut LeaderboardEntry); 6] = [
+            |entry| entry.score += 100,
+            |entry| entry.name.push('!'),
+            |entry| entry.party_size += 1,
+            |entry| entry.num_rounds += 1,
+            |entry| entry.challenge = Some(0),
+            |entry| entry.replay_hash ^= 1,
+        ];
+        for edit in edits {
+            let mut entry = entry.clone();
+            edit(&mut entry);
+            assert!(!entry.is_valid(), "{:?}", entry);
+        }
+    }
+}
@@ -1,7 +1,21 @@
 use std::collections::VecDeque;
 
+pub mod achievement;
+pub use achievement::*;
+pub mod content;
+pub use content::*;
 pub mod data;
 pub use data::*;
+pub mod dialog;
+pub use dialog::*;
+pub mod leaderboard;
+pub use leaderboard::*;
+pub mod profile;
+pub use profile::*;
+pub mod rng;
+pub use rng::*;
+pub mod settings;
+pub use settings::*;
 pub mod state;
 pub use state::*;
 pub mod ui;
@@ -24,9 +38,13 @@ turbo::init! {
             TitleMenu,
             GameMenu { focused: usize, is_starting: bool },
             LoadedGame { id: u8 },
+            Profile { player: Player },
+            Leaderboard { party_size: u8, num_rounds: u8, is_daily: bool },
         },
         cooldown_timer: u32,
         adventure: Option<Adventure>,
+        profiles: ProfileStore,
+        leaderboard: Leaderboard,
         gui: struct GUI {
             commands: VecDeque<enum Command {
                 GoblinList(enum GoblinListEvent {
@@ -37,10 +55,24 @@ turbo::init! {
                     Close,
                     FastForward,
                     Next,
+                    Choose(usize),
                 }),
                 GoblinLootInspector(enum GoblinLootInspectorEvent {
                     Close,
                     SelectLoot(usize),
+                    SelectItem(usize),
+                    Drop,
+                    Give(Player),
+                    Use,
+                    Bribe,
+                    PrevPage,
+                    NextPage,
+                    SwapOverflow,
+                    LeaveOverflow,
+                }),
+                DungeonMap(enum DungeonMapEvent {
+                    Open,
+                    Close,
                 }),
                 PhaseActionSection(enum PhaseActionSectionEvent {
                     Camp(enum CampPhaseAction {
@@ -49,18 +81,18 @@ turbo::init! {
                         RummageConfirmFailure,
                         RummageConfirmSuccess(bool),
                         Bribe,
+                        BribeChoose(HeroKind),
                         ContinueStart,
                         ContinueEnd,
                         BackToDefaultMenu,
                     })
                     Event(enum EventPhaseAction {
-                        TakeRisk,
-                        TakeRiskEnd,
-                        PlayItSafe,
-                        PlayItSafeEnd,
+                        Choose(usize),
+                        ChooseEnd(usize),
                         ConfirmOutcome(bool),
-                        KeepGoingStart,
-                        KeepGoingEnd,
+                        Reroll,
+                        KeepGoingStart(usize),
+                        KeepGoingEnd(usize),
                         TakeABreakStart,
                         TakeABreakEnd,
 
@@ -71,17 +103,33 @@ turbo::init! {
                 camp: enum CampActionMenu {
                     Default,
                     RummageResult,
+                    BribeChoice,
                     BribeResult,
                 }
             },
             goblin_list: struct GoblinList {},
             loot_inspector: Option<struct GoblinLootInspector {
                 player: Player,
-                selected: Option<usize>,
+                selected: Option<enum LootSlot {
+                    Loot(usize),
+                    Item(usize),
+                }>,
+                page: usize,
+                // Set when the bag is opened to pick a bribe
+                bribe_target: Option<HeroKind>,
+            }>,
+            dungeon_map: Option<struct DungeonMap {}>,
+            toasts: VecDeque<struct Toast {
+                msg: String,
+                timer: u32,
             }>,
             goblin_dialog: Option<struct GoblinDialog {
                 player: Player,
+                name: String,
+                script: String,
                 message: String,
+                choices: Vec<DialogPageChoice>,
+                next: Option<usize>,
                 max_len: usize,
                 on_close: Option<Command>,
             }>,
@@ -91,6 +139,8 @@ turbo::init! {
             screen: Screen::TitleMenu,
             cooldown_timer: 0,
             adventure: None,
+            profiles: ProfileStore::new(),
+            leaderboard: Leaderboard::new(),
             gui: GUI {
                 commands: VecDeque::new(),
                 phase_actions_section: PhaseActionsSection {
@@ -98,7 +148,9 @@ turbo::init! {
                 },
                 goblin_list: GoblinList {},
                 loot_inspector: None,
+                dungeon_map: None,
                 goblin_dialog: None,
+                toasts: VecDeque::new(),
             }
         }
     }
@@ -107,14 +159,63 @@ turbo::init! {
 turbo::go! {
     let mut state = GameState::load();
 
+    #[cfg(feature = "hot-reload")]
+    if let Some(old_content) = poll_content_files() {
+        if let Some(ref mut adventure) = state.adventure {
+            adventure.remap_content(&old_content, &content());
+        }
+    }
+
+    // Daily challenges roll their dice from the adventure's own generator
+    seed_rng(state.adventure.as_ref().and_then(|adventure| adventure.rng));
+
     set_camera(0, 0);
     clear(0x000000ff);
 
+    if None == state.adventure {
+        if let Screen::Profile { ref mut player } = state.screen {
+            let owner = Settings::seat_owner(&solana::user_pubkey(), *player);
+            if draw_profile(state.profiles.get(&owner), owner, player) {
+                state.screen = Screen::TitleMenu;
+            }
+            state.gui.draw_toasts();
+            draw_cursor();
+            state.save();
+            return;
+        }
+    }
+
+    if None == state.adventure {
+        if let Screen::Leaderboard { ref mut party_size, ref mut num_rounds, ref mut is_daily } = state.screen {
+            if draw_leaderboard(&state.leaderboard, today(), party_size, num_rounds, is_daily) {
+                state.screen = Screen::TitleMenu;
+            }
+            state.gui.draw_toasts();
+            draw_cursor();
+            state.save();
+            return;
+        }
+    }
+
     if None == state.adventure {
         sprite!("title_bg_2");
-        if mouse(0).left.just_released() {
+        let [_, sh] = resolution();
+        if button(Font::M, 8, sh as i32 - 24, " PROFILE ") {
+            state.screen = Screen::Profile { player: Player::P1 };
+        } else if button(Font::M, 72, sh as i32 - 24, " SCORES ") {
+            state.screen = Screen::Leaderboard {
+                party_size: 1,
+                num_rounds: Settings::new().num_rounds,
+                is_daily: false,
+            };
+        } else if button(Font::M, 130, sh as i32 - 24, " DAILY ") {
+            let _ = state.adventure.insert(Adventure::daily(solana::user_pubkey(), today()));
+        } else if mouse(0).left.just_released() {
             let _ = state.adventure.insert(Adventure::new(solana::user_pubkey()));
         }
+        #[cfg(feature = "hot-reload")]
+        draw_content_reload_error();
+        state.gui.draw_toasts();
         draw_cursor();
         state.save();
         return;
@@ -134,15 +235,33 @@ turbo::go! {
                     // Goblins
                     text!("Players", x = x, y = y, color = FG);
                     let y = y + 12;
-                    text!("How many goblins in your party?", x = x, y = y, font = Font::S, color = FG);
+                    text!("Click goblins for traits, name halves to rename.", x = x, y = y, font = Font::S, color = FG);
                     let y = y + 12;
                     let w = (sw - (x * 2) as u32) / 4;
                     let players = &[Player::P1, Player::P2, Player::P3, Player::P4];
                     for (i, player) in players.iter().enumerate() {
                         let x = x + (i as i32 % 4) * w as i32;
                         let y = y + (i as i32 / 4) * w as i32;
-                        div(w - 1, w, x, y);
-                        sprite!(&format!("goblin_{}", i + 1), x = x + 12, y = y + 16);
+                        // Each seat cycles through the traits, starting over with a fresh goblin
+                        if div(w - 1, w, x, y) {
+                            if let Some(goblin) = goblins.get_mut(player) {
+                                let [mx, my] = mouse(0).position;
+                                if my < y + 18 {
+                                    // The left half of the name picks its first syllable, the right half its second
+                                    let syllable = if mx < x + w as i32 / 2 { 0 } else { 1 };
+                                    goblin.name = Goblin::cycle_name(&goblin.name, syllable);
+                                } else {
+                                    let name = goblin.name.clone();
+                                    *goblin = Goblin { name, ..Goblin::with_trait(TraitKind::cycle(goblin.trait_kind)) };
+                                }
+                            }
+                        }
+                        sprite!(&format!("goblin_{}", i + 1), x = x + 12, y = y + 18);
+                        if let Some(goblin) = goblins.get(player) {
+                            text!(&goblin.name.to_ascii_uppercase(), x = x + 4, y = y + 12, font = Font::S, color = FG);
+                            let name = goblin.trait_data().map_or("NO TRAIT", |data| data.name);
+                            text!(name, x = x + 4, y = y + 52, font = Font::S, color = FG);
+                        }
                         if *player != Player::P1 {
                             if goblins.contains_key(&player) {
                                 if button(Font::M, x + 1, y + 61, " Remove  ") {
@@ -150,7 +269,7 @@ turbo::go! {
                                     goblins.remove(player);
                                 };
                             } else {
-                                rect!(w = 32, h = 32, x = x + 12, y = y + 16, fill = 0x000000ee);
+                                rect!(w = 32, h = 32, x = x + 12, y = y + 18, fill = 0x000000ee);
                                                                     //  Recruit
                                 if ibutton(Font::M, x + 1, y + 61, " Recruit ") {
                                     turbo::println!("Add player!");
@@ -163,25 +282,61 @@ turbo::go! {
                             text!(&format!("{:?}", player), x = x + 4, y = y + 4, color = FG);
                         }
                     }
-                    let y = y + 96;
+                    let y = y + 84;
 
-                    // Rounds
-                    text!("Rounds", x = x, y = y, color = FG);
-                    let y = y + 12;
-                    text!("How long should your adventure last?", x = x, y = y, font = Font::S, color = FG);
+                    // Rules, starting from a preset
+                    let preset = settings.preset().map_or("Custom".to_string(), |preset| format!("{:?}", preset));
+                    text!(&format!("Rules: {}", preset), x = x, y = y, color = FG);
                     let y = y + 12;
-                    div(32, 32, x, y);
-                    text!(&format!("{:0>3}", settings.num_rounds), x = x + 9, y = y + 13, color = FG);
-                    let x = x + 33;
-                    if ibutton(Font::M, x, y, "+") {
-                        turbo::println!("INCREASE!");
-                        settings.num_rounds += 1;
-                    };
-                    let y = y + 16;
-                    if ibutton(Font::M, x, y, "-") {
-                        turbo::println!("DECREASE!");
-                        settings.num_rounds -= 1;
-                    };
+                    let i = settings.preset().and_then(|p| Preset::ALL.iter().position(|q| *q == p));
+                    if let Some(step) = stepper(x, y, "PRESET", &preset.to_ascii_uppercase()) {
+                        let n = Preset::ALL.len() as i32;
+                        let i = i.map_or(0, |i| (i as i32 + step as i32 + n) % n);
+                        settings.apply_preset(Preset::ALL[i as usize]);
+                    }
+                    if let Some(step) = stepper(x, y + 18, "ROUNDS", &format!("{:0>3}", settings.num_rounds)) {
+                        settings.add_rounds(step);
+                    }
+                    if let Some(step) = stepper(x, y + 36, "HEALTH", &settings.starting_health.to_string()) {
+                        settings.add_starting_health(step);
+                    }
+                    let msg = &format!("{:?}", settings.rummage_difficulty).to_ascii_uppercase();
+                    if let Some(step) = stepper(x, y + 54, "RUMMAGE", msg) {
+                        #[rustfmt::skip]
+                        let difficulty = if step > 0 { settings.rummage_difficulty.next() } else { settings.rummage_difficulty.prev() };
+                        settings.rummage_difficulty = difficulty;
+                    }
+
+                    // Heroes in play
+                    let x = 132;
+                    text!("HEROES", x = x, y = y + 2, font = Font::S, color = FG);
+                    for (i, hero) in HeroKind::ALL.iter().enumerate() {
+                        let msg = &format!("{:?}", hero)[..2].to_ascii_uppercase();
+                        let x = x + i as i32 * 20;
+                        #[rustfmt::skip]
+                        let (color, fill) = if settings.heroes.contains_key(hero) { (BG, FG) } else { (FG, BG) };
+                        if cbutton(Font::S, x, y + 10, Some(18), color, fill, FG, msg) {
+                            settings.toggle_hero(*hero);
+                        }
+                    }
+
+                    // Turn order
+                    let msg = settings.turn_order_mode.name();
+                    if let Some(step) = stepper(x, y + 28, "ORDER", msg) {
+                        #[rustfmt::skip]
+                        let mode = if step > 0 { settings.turn_order_mode.next() } else { settings.turn_order_mode.prev() };
+                        settings.turn_order_mode = mode;
+                    }
+
+                    // Variant rules
+                    for (i, variant) in VariantRule::ALL.iter().enumerate() {
+                        let y = y + 46 + i as i32 * 15;
+                        #[rustfmt::skip]
+                        let (color, fill) = if settings.has_variant(*variant) { (BG, FG) } else { (FG, BG) };
+                        if cbutton(Font::S, x, y, Some(98), color, fill, FG, variant.name()) {
+                            settings.toggle_variant(*variant);
+                        }
+                    }
 
                     // Next
                     let sh = sh as i32;
@@ -194,35 +349,42 @@ turbo::go! {
                     if ibutton(Font::L, x + 128, y, "   START >  ") {
                         turbo::println!("START");
                         if adventure.start_adventure().is_ok() {
-                            let msg = ENTERING_CAMP_DIALOG[rand() as usize % ENTERING_CAMP_DIALOG.len()];
-                            state.gui.open_goblin_dialog(Player::P1, msg, None);
+                            adventure.update_replay_hash();
+                            if let AdventureState::Started(goblins, _, turn, _, _, _) = &adventure.state {
+                                let content = content();
+                                let msg = content.random_dialog(DialogKey::EnteringCamp);
+                                let ctx = DialogContext::new(&goblins[&turn.player], &content.camp.name);
+                                state.gui.open_goblin_dialog(turn.player, msg, &ctx, None);
+                            }
                         }
                     };
                 }
-                AdventureState::Started(goblins, settings, turn, phase) => {
+                AdventureState::Started(goblins, settings, turn, phase, dungeon, sack) => {
+                    let content = content();
+
                     // Phase Actions Section
                     match phase {
                         // Event Phase
                         AdventurePhase::Event(event_phase) => {
-                            let data = EventLocationData::get(event_phase.location);
-                            let num_locations = ALL_EVENT_LOCATION_DATA.len();
+                            let data = content.location(event_phase.location);
+                            let num_locations = content.locations.len();
                             let num_scenarios = data.scenarios.len();
                             // Debug
                             if gamepad(0).left.just_pressed() {
                                 event_phase.location = (event_phase.location - 1).min(num_locations - 1) % num_locations;
-                                event_phase.scenario %= EventLocationData::get(event_phase.location).scenarios.len();
+                                event_phase.scenario %= content.location(event_phase.location).scenarios.len();
                             } else if gamepad(0).right.just_pressed() {
                                 event_phase.location = (event_phase.location + 1) % num_locations;
-                                event_phase.scenario %= EventLocationData::get(event_phase.location).scenarios.len();
+                                event_phase.scenario %= content.location(event_phase.location).scenarios.len();
                             } else if gamepad(0).up.just_pressed() {
                                 event_phase.scenario = (event_phase.scenario - 1).min(num_scenarios - 1) % num_scenarios;
                             } else if gamepad(0).down.just_pressed() {
                                 event_phase.scenario = (event_phase.scenario + 1) % num_scenarios;
                             }
 
-                            let image = data.images[0];
+                            let image = &data.images[0];
                             sprite!(image);
-                            if let Some(event) = state.gui.phase_actions_section.draw_event_actions(&event_phase) {
+                            if let Some(event) = state.gui.phase_actions_section.draw_event_actions(&event_phase, &goblins, &turn, &dungeon, &settings) {
                                 if !state.gui.is_overlay_open() {
                                     let event = PhaseActionSectionEvent::Event(event);
                                     // turbo::println!("event {:?}", event);
@@ -232,10 +394,10 @@ turbo::go! {
                         }
                         // Camp Phase
                         AdventurePhase::Camp(camp_phase) => {
-                            let data = &CAMP_LOCATION_DATA;
-                            let image = data.images[0];
+                            let data = &content.camp;
+                            let image = &data.images[0];
                             sprite!(image);
-                            if let Some(event) = state.gui.phase_actions_section.draw_camp_actions(&camp_phase) {
+                            if let Some(event) = state.gui.phase_actions_section.draw_camp_actions(&camp_phase, &goblins, &sack, &settings) {
                                 if !state.gui.is_overlay_open() {
                                     let event = PhaseActionSectionEvent::Camp(event);
                                     // turbo::println!("event {:?}", event);
@@ -258,9 +420,27 @@ turbo::go! {
                         }
                     }
 
+                    // Dungeon Map
+                    if let Some(event) = DungeonMap::draw_button() {
+                        if !state.gui.is_overlay_open() {
+                            state.gui.dispatch(Command::DungeonMap(event));
+                        }
+                    }
+                    if let Some(ref mut map) = state.gui.dungeon_map {
+                        if let Some(event) = map.draw(&dungeon) {
+                            state.gui.dispatch(Command::DungeonMap(event));
+                        }
+                    }
+
                     // Goblin Loot Inspector
+                    // Loot that didn't fit in a bag has to be dealt with before moving on
+                    if let Some(player) = goblins.iter().find_map(|(p, g)| (!g.overflow.is_empty()).then_some(*p)) {
+                        if !state.gui.is_overlay_open() {
+                            state.gui.open_goblin_loot_inspector(player);
+                        }
+                    }
                     if let Some(ref mut inspector) = state.gui.loot_inspector {
-                        if let Some(event) = inspector.draw(&goblins) {
+                        if let Some(event) = inspector.draw(&goblins, &turn, &phase) {
                             turbo::println!("event {:?}", event);
                             state.gui.dispatch(Command::GoblinLootInspector(event));
                         }
@@ -280,14 +460,28 @@ turbo::go! {
                         CampRummageTakeLoot,
                         CampRummageLeaveLoot,
                         EventStart,
-                        EventTakeRisk,
-                        EventPlayItSafe,
+                        EventMakeChoice(usize),
                         EventHandleOutcome,
-                        KeepGoing,
+                        EventReroll,
+                        KeepGoing(usize),
                         TakeABreak,
+                        DropLoot(usize),
+                        GiveLoot(usize, Player),
+                        BribeHero(usize, HeroKind),
+                        UseItem(usize),
+                        DropItem(usize),
+                        SwapOverflow(Player, usize),
+                        LeaveOverflow(Player),
                     }
                     let mut action = None;
 
+                    // Dialog scripts can refer to the current location
+                    let location = match phase {
+                        AdventurePhase::Event(event_phase) => &content.location(event_phase.location).name,
+                        AdventurePhase::Camp(_) => &content.camp.name,
+                    };
+                    let ctx = DialogContext::new(&goblins[&turn.player], location);
+
                     // Consume GUI Commands
                     let mut cmd = state.gui.commands.pop_front();
                     while cmd != None {
@@ -295,37 +489,23 @@ turbo::go! {
                         match cmd.take() {
                             Some(Command::PhaseActionSection(e)) => match e {
                                 PhaseActionSectionEvent::Event(e) => match e {
-                                    EventPhaseAction::PlayItSafe => {
-                                        let event = PhaseActionSectionEvent::Event(EventPhaseAction::PlayItSafeEnd);
+                                    EventPhaseAction::Choose(i) => {
+                                        let event = PhaseActionSectionEvent::Event(EventPhaseAction::ChooseEnd(i));
                                         let cmd = Command::PhaseActionSection(event);
                                         let msg = match phase {
                                             AdventurePhase::Event(event_phase) => {
-                                                let data = EventLocationData::get(event_phase.location);
-                                                let actions = &data.scenarios[event_phase.scenario].actions;
-                                                actions[1].dialog[0]
-                                            }
-                                            _ => "Better not risk it..."
-                                        };
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
-                                    }
-                                    EventPhaseAction::PlayItSafeEnd => {
-                                        action = Some(Action::EventPlayItSafe);
-                                    }
-                                    EventPhaseAction::TakeRisk => {
-                                        let event = PhaseActionSectionEvent::Event(EventPhaseAction::TakeRiskEnd);
-                                        let cmd = Command::PhaseActionSection(event);
-                                        let msg = match phase {
-                                            AdventurePhase::Event(event_phase) => {
-                                                let data = EventLocationData::get(event_phase.location);
-                                                let actions = &data.scenarios[event_phase.scenario].actions;
-                                                actions[0].dialog[0]
+                                                let data = content.location(event_phase.location);
+                                                match data.scenarios[event_phase.scenario].actions.get(i) {
+                                                    Some(action) => action.dialog[rand() as usize % action.dialog.len()].as_str(),
+                                                    None => continue,
+                                                }
                                             }
-                                            _ => "Fuck it, we ball!"
+                                            _ => continue,
                                         };
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
-                                    EventPhaseAction::TakeRiskEnd => {
-                                        action = Some(Action::EventTakeRisk);
+                                    EventPhaseAction::ChooseEnd(i) => {
+                                        action = Some(Action::EventMakeChoice(i));
                                     }
                                     EventPhaseAction::ConfirmOutcome(should_handle_outcome) => {
                                         if should_handle_outcome {
@@ -335,33 +515,38 @@ turbo::go! {
                                             let cmd = Command::PhaseActionSection(event);
                                             let msg = match phase {
                                                 AdventurePhase::Event(event_phase) => {
-                                                    if let Some(EventPhaseOutcome { choice, effect, accepted: _ }) = &event_phase.outcome {
-                                                        let data = EventLocationData::get(event_phase.location);
+                                                    if let Some(EventPhaseOutcome { choice, effect, .. }) = &event_phase.outcome {
+                                                        let data = content.location(event_phase.location);
                                                         let action = &data.scenarios[event_phase.scenario].actions[*choice];
-                                                        action.outcomes[*effect].dialog[0]
+                                                        action.outcomes[*effect].dialog[0].as_str()
                                                     } else {
                                                         continue
                                                     }
                                                 }
                                                 _ => continue,
                                             };
-                                            state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                            state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                         }
                                     }
-                                    EventPhaseAction::KeepGoingStart => {
-                                        let event = PhaseActionSectionEvent::Event(EventPhaseAction::KeepGoingEnd);
+                                    EventPhaseAction::Reroll => {
+                                        action = Some(Action::EventReroll);
+                                    }
+                                    EventPhaseAction::KeepGoingStart(path) => {
+                                        let event = PhaseActionSectionEvent::Event(EventPhaseAction::KeepGoingEnd(path));
                                         let cmd = Command::PhaseActionSection(event);
-                                        let msg = KEEP_GOING_DIALOG[rand() as usize % KEEP_GOING_DIALOG.len()];
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        #[rustfmt::skip]
+                                        let key = if goblins[&turn.player].greed >= Goblin::MAX_GREED { DialogKey::GreedyFit } else { DialogKey::KeepGoing };
+                                        let msg = content.random_dialog(key);
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
-                                    EventPhaseAction::KeepGoingEnd => {
-                                        action = Some(Action::KeepGoing);
+                                    EventPhaseAction::KeepGoingEnd(path) => {
+                                        action = Some(Action::KeepGoing(path));
                                     }
                                     EventPhaseAction::TakeABreakStart => {
                                         let event = PhaseActionSectionEvent::Event(EventPhaseAction::TakeABreakEnd);
                                         let cmd = Command::PhaseActionSection(event);
-                                        let msg = TAKE_A_BREAK_DIALOG[rand() as usize % TAKE_A_BREAK_DIALOG.len()];
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        let msg = content.random_dialog(DialogKey::TakeABreak);
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
                                     EventPhaseAction::TakeABreakEnd => {
                                         action = Some(Action::TakeABreak);
@@ -371,8 +556,8 @@ turbo::go! {
                                     CampPhaseAction::RummageStart => {
                                         let event = PhaseActionSectionEvent::Camp(CampPhaseAction::RummageEnd);
                                         let cmd = Command::PhaseActionSection(event);
-                                        let msg = LOOT_RUMMAGE_DIALOG[rand() as usize % LOOT_RUMMAGE_DIALOG.len()];
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        let msg = content.random_dialog(DialogKey::LootRummage);
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
                                     CampPhaseAction::RummageEnd => {
                                         action = Some(Action::CampRummageForLoot);
@@ -381,14 +566,14 @@ turbo::go! {
                                     CampPhaseAction::RummageConfirmFailure => {
                                         let msg = match phase {
                                             AdventurePhase::Camp(camp_phase) => match &camp_phase.rummage_result {
-                                                Some(RummageResult::Fail) => LOOT_RUMMAGE_FAIL_ACCEPT_DIALOG[rand() as usize % LOOT_RUMMAGE_FAIL_ACCEPT_DIALOG.len()],
+                                                Some(RummageResult::Fail) => content.random_dialog(DialogKey::LootRummageFailAccept),
                                                 _ => UNREACHABLE_DIALOG
                                             }
                                             _ => UNREACHABLE_DIALOG
                                         };
                                         let event = PhaseActionSectionEvent::Camp(CampPhaseAction::BackToDefaultMenu);
                                         let cmd = Command::PhaseActionSection(event);
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
                                     CampPhaseAction::RummageConfirmSuccess(did_take_loot) => {
                                         let msg = match phase {
@@ -396,10 +581,10 @@ turbo::go! {
                                                 Some(RummageResult::Success { .. })=> {
                                                     if did_take_loot {
                                                         action = Some(Action::CampRummageTakeLoot);
-                                                        &LOOT_RUMMAGE_TAKE_LOOT_DIALOG[rand() as usize % LOOT_RUMMAGE_TAKE_LOOT_DIALOG.len()]
+                                                        content.random_dialog(DialogKey::LootRummageTakeLoot)
                                                     } else {
                                                         action = Some(Action::CampRummageLeaveLoot);
-                                                        &LOOT_RUMMAGE_LEAVE_LOOT_DIALOG[rand() as usize % LOOT_RUMMAGE_LEAVE_LOOT_DIALOG.len()]
+                                                        content.random_dialog(DialogKey::LootRummageLeaveLoot)
                                                     }
                                                 },
                                                 _ => UNREACHABLE_DIALOG
@@ -408,18 +593,21 @@ turbo::go! {
                                         };
                                         let event = PhaseActionSectionEvent::Camp(CampPhaseAction::BackToDefaultMenu);
                                         let cmd = Command::PhaseActionSection(event);
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
                                     CampPhaseAction::Bribe => {
-                                        // TODO
-                                        let msg = UNIMPLEMENTED_DIALOG[rand() as usize % UNIMPLEMENTED_DIALOG.len()];
-                                        state.gui.open_goblin_dialog(turn.player, msg, None);
+                                        state.gui.phase_actions_section.camp = CampActionMenu::BribeChoice;
+                                    }
+                                    CampPhaseAction::BribeChoose(hero) => {
+                                        // The bribe is picked from the goblin's loot bag
+                                        state.gui.open_goblin_loot_inspector(turn.player);
+                                        state.gui.set_loot_inspector_bribe_target(hero);
                                     }
                                     CampPhaseAction::ContinueStart => {
                                         let event = PhaseActionSectionEvent::Camp(CampPhaseAction::ContinueEnd);
                                         let cmd = Command::PhaseActionSection(event);
-                                        let msg = KEEP_GOING_DIALOG[rand() as usize % KEEP_GOING_DIALOG.len()];
-                                        state.gui.open_goblin_dialog(turn.player, msg, Some(cmd));
+                                        let msg = content.random_dialog(DialogKey::KeepGoing);
+                                        state.gui.open_goblin_dialog(turn.player, msg, &ctx, Some(cmd));
                                     }
                                     CampPhaseAction::ContinueEnd => {
                                         action = Some(Action::EventStart);
@@ -433,14 +621,14 @@ turbo::go! {
                                 GoblinListEvent::OpenGoblinDialog(player) => {
                                     let msg = match phase {
                                         AdventurePhase::Event(event_phase) => {
-                                            let data = EventLocationData::get(event_phase.location);
-                                            data.dialog[0]
+                                            let data = content.location(event_phase.location);
+                                            data.dialog[rand() as usize % data.dialog.len()].as_str()
                                         }
                                         AdventurePhase::Camp(_camp_phase) => {
-                                            CAMP_LOCATION_DATA.dialog[rand() as usize % CAMP_LOCATION_DATA.dialog.len()]
+                                            content.camp.dialog[rand() as usize % content.camp.dialog.len()].as_str()
                                         }
                                     };
-                                    state.gui.open_goblin_dialog(player, msg, None);
+                                    state.gui.open_goblin_dialog(player, msg, &DialogContext::new(&goblins[&player], location), None);
                                 }
                                 GoblinListEvent::OpenGoblinLootInspector(player) => {
                                     state.gui.open_goblin_loot_inspector(player);
@@ -454,15 +642,91 @@ turbo::go! {
                                     state.gui.fast_forward_goblin_dialog();
                                 }
                                 GoblinDialogEvent::Next => {
-                                    // TODO
+                                    if let Some((player, target)) = state.gui.goblin_dialog.as_ref().map(|d| (d.player, d.next)) {
+                                        let ctx = DialogContext::new(&goblins[&player], location);
+                                        cmd = state.gui.goto_goblin_dialog_node(target, &ctx);
+                                    }
+                                }
+                                GoblinDialogEvent::Choose(i) => {
+                                    if let Some((player, target)) = state.gui.goblin_dialog.as_ref().and_then(|d| Some((d.player, d.choices.get(i)?.target))) {
+                                        let ctx = DialogContext::new(&goblins[&player], location);
+                                        cmd = state.gui.goto_goblin_dialog_node(target, &ctx);
+                                    }
+                                }
+                            }
+                            Some(Command::DungeonMap(e)) => match e {
+                                DungeonMapEvent::Open => {
+                                    state.gui.open_dungeon_map();
+                                }
+                                DungeonMapEvent::Close => {
+                                    state.gui.close_dungeon_map();
                                 }
                             }
                             Some(Command::GoblinLootInspector(e)) => match e {
                                 GoblinLootInspectorEvent::Close => {
                                     state.gui.close_goblin_loot_inspector();
                                 }
-                                GoblinLootInspectorEvent::SelectLoot(_i) => {
-                                    // TODO
+                                GoblinLootInspectorEvent::SelectLoot(i) => {
+                                    if let Some(ref mut inspector) = state.gui.loot_inspector {
+                                        if i < goblins[&inspector.player].loot.len() {
+                                            inspector.select(LootSlot::Loot(i));
+                                        }
+                                    }
+                                }
+                                GoblinLootInspectorEvent::SelectItem(i) => {
+                                    if let Some(ref mut inspector) = state.gui.loot_inspector {
+                                        if i < goblins[&inspector.player].items.len() {
+                                            inspector.select(LootSlot::Item(i));
+                                        }
+                                    }
+                                }
+                                GoblinLootInspectorEvent::Drop => match state.gui.take_loot_inspector_selection() {
+                                    Some(LootSlot::Loot(i)) => action = Some(Action::DropLoot(i)),
+                                    Some(LootSlot::Item(i)) => action = Some(Action::DropItem(i)),
+                                    None => {}
+                                }
+                                GoblinLootInspectorEvent::Give(player) => {
+                                    if let Some(LootSlot::Loot(i)) = state.gui.take_loot_inspector_selection() {
+                                        action = Some(Action::GiveLoot(i, player));
+                                    }
+                                }
+                                GoblinLootInspectorEvent::Use => {
+                                    if let Some(LootSlot::Item(i)) = state.gui.take_loot_inspector_selection() {
+                                        action = Some(Action::UseItem(i));
+                                    }
+                                }
+                                GoblinLootInspectorEvent::PrevPage => {
+                                    if let Some(ref mut inspector) = state.gui.loot_inspector {
+                                        inspector.page = inspector.page.saturating_sub(1);
+                                    }
+                                }
+                                GoblinLootInspectorEvent::NextPage => {
+                                    if let Some(ref mut inspector) = state.gui.loot_inspector {
+                                        let bag_size = goblins[&inspector.player].bag_size as usize;
+                                        if (inspector.page + 1) * GoblinLootInspector::PAGE_LEN < bag_size {
+                                            inspector.page += 1;
+                                        }
+                                    }
+                                }
+                                GoblinLootInspectorEvent::SwapOverflow => {
+                                    if let Some(player) = state.gui.loot_inspector.as_ref().map(|inspector| inspector.player) {
+                                        if let Some(LootSlot::Loot(i)) = state.gui.take_loot_inspector_selection() {
+                                            action = Some(Action::SwapOverflow(player, i));
+                                        }
+                                    }
+                                }
+                                GoblinLootInspectorEvent::LeaveOverflow => {
+                                    if let Some(player) = state.gui.loot_inspector.as_ref().map(|inspector| inspector.player) {
+                                        action = Some(Action::LeaveOverflow(player));
+                                    }
+                                }
+                                GoblinLootInspectorEvent::Bribe => {
+                                    let hero = state.gui.loot_inspector.as_ref().and_then(|inspector| inspector.bribe_target);
+                                    if let (Some(LootSlot::Loot(i)), Some(hero)) = (state.gui.take_loot_inspector_selection(), hero) {
+                                        action = Some(Action::BribeHero(i, hero));
+                                        state.gui.close_goblin_loot_inspector();
+                                        state.gui.phase_actions_section.camp = CampActionMenu::BribeResult;
+                                    }
                                 }
                             }
                             _ => {
@@ -471,6 +735,7 @@ turbo::go! {
                         }
                     }
                     state.gui.commands.clear();
+                    let is_acting = action.is_some();
                     match action {
                         Some(Action::CampRummageForLoot) => {
                             if adventure.rummage_for_loot().is_err() {
@@ -492,14 +757,9 @@ turbo::go! {
                                 turbo::println!("Couldn't start event");
                             }
                         }
-                        Some(Action::EventTakeRisk) => {
-                            if adventure.event_make_choice(0).is_err() {
-                                turbo::println!("Couldn't take risk");
-                            }
-                        }
-                        Some(Action::EventPlayItSafe) => {
-                            if adventure.event_make_choice(1).is_err() {
-                                turbo::println!("Couldn't play it safe");
+                        Some(Action::EventMakeChoice(i)) => {
+                            if adventure.event_make_choice(i).is_err() {
+                                turbo::println!("Couldn't make choice {}", i);
                             }
                         }
                         Some(Action::EventHandleOutcome) => {
@@ -507,8 +767,13 @@ turbo::go! {
                                 turbo::println!("Couldn't handle outcome");
                             }
                         }
-                        Some(Action::KeepGoing) => {
-                            if adventure.keep_going().is_err() {
+                        Some(Action::EventReroll) => {
+                            if adventure.event_reroll().is_err() {
+                                turbo::println!("Couldn't reroll");
+                            }
+                        }
+                        Some(Action::KeepGoing(path)) => {
+                            if adventure.keep_going(path).is_err() {
                                 turbo::println!("Couldn't keep going");
                             }
                         }
@@ -517,13 +782,100 @@ turbo::go! {
                                 turbo::println!("Couldn't take a break");
                             }
                         }
+                        Some(Action::DropLoot(i)) => {
+                            if adventure.drop_loot(i).is_err() {
+                                turbo::println!("Couldn't drop loot {}", i);
+                            }
+                        }
+                        Some(Action::GiveLoot(i, player)) => {
+                            if adventure.give_loot(i, player).is_err() {
+                                turbo::println!("Couldn't give loot {} to {:?}", i, player);
+                            }
+                        }
+                        Some(Action::BribeHero(i, hero)) => {
+                            if adventure.bribe_hero(i, hero).is_err() {
+                                turbo::println!("Couldn't bribe {:?} with loot {}", hero, i);
+                            }
+                        }
+                        Some(Action::UseItem(i)) => {
+                            if adventure.use_item(i).is_err() {
+                                turbo::println!("Couldn't use item {}", i);
+                            }
+                        }
+                        Some(Action::DropItem(i)) => {
+                            if adventure.drop_item(i).is_err() {
+                                turbo::println!("Couldn't drop item {}", i);
+                            }
+                        }
+                        Some(Action::SwapOverflow(player, i)) => {
+                            if adventure.swap_overflow(player, i).is_err() {
+                                turbo::println!("Couldn't swap loot {} for {:?}", i, player);
+                            }
+                        }
+                        Some(Action::LeaveOverflow(player)) => {
+                            if adventure.leave_overflow(player).is_err() {
+                                turbo::println!("Couldn't leave loot for {:?}", player);
+                            }
+                        }
                         None => {
                             // noop
                         }
                     }
+                    if is_acting {
+                        adventure.update_replay_hash();
+                    }
                 }
-                AdventureState::Complete(_goblins, _settings) => {
-                    //
+                AdventureState::Complete(goblins, _settings, sack) => {
+                    sprite!("parchment_bg");
+                    let [sw, sh] = resolution();
+                    let (x, y) = (8, 4);
+                    rect!(w = sw, h = 16, fill = BG);
+                    let msg = if adventure.challenge.is_some() { "DAILY COMPLETE" } else { "ADVENTURE COMPLETE" };
+                    text!(msg, font = Font::L, x = x, y = y, color = FG);
+                    let y = y + 24;
+
+                    // Party Sack
+                    text!("Party Sack", x = x, y = y, color = FG);
+                    let y = y + 12;
+                    let msg = &format!("${} in loot, {} pieces missing", sack.value(), sack.missing());
+                    text!(msg, x = x, y = y, font = Font::S, color = FG);
+                    let y = y + 8;
+                    let num_goblins = goblins.len();
+                    let msg = &format!("Each goblin is paid ${}", sack.wage(num_goblins));
+                    text!(msg, x = x, y = y, font = Font::S, color = FG);
+                    let y = y + 12;
+
+                    // Results
+                    text!("Results", x = x, y = y, color = FG);
+                    let y = y + 12;
+                    let mut players = goblins.keys().copied().collect::<Vec<_>>();
+                    players.sort_by_key(|player| std::cmp::Reverse(sack.score(&goblins[player], num_goblins)));
+                    for (i, player) in players.iter().enumerate() {
+                        let y = y + i as i32 * 34;
+                        let goblin = &goblins[player];
+                        div(sw - 16, 33, x, y);
+                        sprite!(&format!("goblin_{}", player.index() + 1), x = x, y = y);
+                        let x = x + 40;
+                        let msg = &format!("#{} {:?} {}", i + 1, player, goblin.name);
+                        text!(msg, x = x, y = y + 4, color = FG);
+                        #[rustfmt::skip]
+                        let msg = &if goblin.is_fired {
+                            format!("LOOT ${}  FIRED", goblin.loot_value())
+                        } else {
+                            format!("LOOT ${}  WAGE ${}", goblin.loot_value(), sack.wage(num_goblins))
+                        };
+                        text!(msg, x = x, y = y + 16, font = Font::S, color = FG);
+                        let msg = &format!("${:0>3}", sack.score(goblin, num_goblins));
+                        text!(msg, x = sw as i32 - 56, y = y + 12, font = Font::L, color = FG);
+                    }
+
+                    // Back
+                    let sh = sh as i32;
+                    let x = 4 + 4;
+                    let y = sh - 32;
+                    if button(Font::L, x, y, "   TITLE    ") {
+                        go_to_title = true;
+                    };
                 }
             }
 
@@ -534,9 +886,27 @@ turbo::go! {
                 turbo::println!("{:#?}", adventure);
             }
         }
+        if let Some(ref mut adventure) = state.adventure {
+            adventure.rng = rng_state();
+            // Adventures count towards everyone's career as soon as they're complete
+            if adventure.events.iter().any(|event| matches!(event, AdventureEvent::Completed { .. })) {
+                let _ = state.leaderboard.record(adventure);
+                if let Ok(unlocked) = state.profiles.record(adventure) {
+                    for (_owner, cosmetic) in unlocked {
+                        state.gui.push_toast(&format!("UNLOCKED: {}", cosmetic.name()));
+                    }
+                }
+            }
+            for achievement in state.profiles.unlock_achievements(adventure) {
+                state.gui.push_toast(&format!("ACHIEVEMENT: {}", achievement.name()));
+            }
+        }
         if go_to_title {
             state.adventure = None;
         }
+        #[cfg(feature = "hot-reload")]
+        draw_content_reload_error();
+        state.gui.draw_toasts();
         draw_cursor();
         state.save();
         return;
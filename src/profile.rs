@@ -0,0 +1,175 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+use turbo::{borsh, solana::solana_sdk};
+
+use crate::{Achievement, Adventure, AdventureState, Rarity};
+
+/// Career stats that carry over from one adventure to the next, one profile
+/// per seat played from a wallet. See `Settings::seat_owner`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProfileStore {
+    pub profiles: Vec<Profile>,
+}
+impl ProfileStore {
+    pub fn new() -> Self {
+        Self { profiles: vec![] }
+    }
+    pub fn get(&self, owner: &Pubkey) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.owner == *owner)
+    }
+    pub fn get_or_insert(&mut self, owner: &Pubkey) -> &mut Profile {
+        let i = match self.profiles.iter().position(|profile| profile.owner == *owner) {
+            Some(i) => i,
+            None => {
+                self.profiles.push(Profile::new(*owner));
+                self.profiles.len() - 1
+            }
+        };
+        &mut self.profiles[i]
+    }
+    /// Adds a complete adventure to the profile of every goblin's owner.
+    /// Returns the cosmetics each owner unlocked along the way.
+    pub fn record(&mut self, adventure: &Adventure) -> Result<Vec<(Pubkey, Cosmetic)>, ()> {
+        if let AdventureState::Complete(goblins, settings, sack) = &adventure.state {
+            let winner = sack.winner(goblins);
+            let mut unlocked = vec![];
+            for (owner, player) in &settings.goblin_owners {
+                let Some(goblin) = goblins.get(player) else {
+                    continue;
+                };
+                let profile = self.get_or_insert(owner);
+                profile.adventures_played += 1;
+                if winner == Some(*player) {
+                    profile.wins += 1;
+                }
+                for loot in &goblin.loot {
+                    let i = Rarity::ALL.iter().position(|r| *r == loot.rarity).unwrap();
+                    profile.loot[i] += 1;
+                }
+                profile.times_caught += goblin.times_caught as u32;
+                profile.slap_fights_won += goblin.slap_fights_won as u32;
+                for cosmetic in profile.unlock_cosmetics() {
+                    unlocked.push((*owner, cosmetic));
+                }
+            }
+            return Ok(unlocked);
+        }
+        return Err(());
+    }
+    /// Drains an adventure's events, unlocking achievements for the owners of
+    /// the goblins involved. Returns the newly unlocked ones.
+    pub fn unlock_achievements(&mut self, adventure: &mut Adventure) -> Vec<Achievement> {
+        let (goblins, settings) = match &adventure.state {
+            AdventureState::Preparing(goblins, settings) => (goblins, settings),
+            AdventureState::Started(goblins, settings, ..) => (goblins, settings),
+            AdventureState::Complete(goblins, settings, _) => (goblins, settings),
+        };
+        let mut unlocked = vec![];
+        for event in adventure.events.drain(..) {
+            let player = event.player();
+            let Some(goblin) = goblins.get(&player) else {
+                continue;
+            };
+            for (owner, _) in settings.goblin_owners.iter().filter(|(_, p)| **p == player) {
+                let profile = self.get_or_insert(owner);
+                for achievement in Achievement::ALL {
+                    if achievement.is_earned(&event, goblin) && profile.achieve(*achievement) {
+                        unlocked.push(*achievement);
+                    }
+                }
+            }
+        }
+        unlocked
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    pub owner: Pubkey,
+    pub adventures_played: u32,
+    pub wins: u32,
+    // Loot carried home, counted by `Rarity::ALL`
+    pub loot: [u32; 5],
+    pub times_caught: u32,
+    pub slap_fights_won: u32,
+    pub cosmetics: Vec<Cosmetic>,
+    pub achievements: Vec<Achievement>,
+}
+impl Profile {
+    pub fn new(owner: Pubkey) -> Self {
+        Self {
+            owner,
+            adventures_played: 0,
+            wins: 0,
+            loot: [0; 5],
+            times_caught: 0,
+            slap_fights_won: 0,
+            cosmetics: vec![],
+            achievements: vec![],
+        }
+    }
+    pub fn total_loot(&self) -> u32 {
+        self.loot.iter().sum()
+    }
+    /// Unlocks an achievement, returning whether it's new.
+    pub fn achieve(&mut self, achievement: Achievement) -> bool {
+        if self.achievements.contains(&achievement) {
+            return false;
+        }
+        self.achievements.push(achievement);
+        true
+    }
+    /// Unlocks every cosmetic the profile now qualifies for, returning the
+    /// new ones.
+    pub fn unlock_cosmetics(&mut self) -> Vec<Cosmetic> {
+        let unlocked = Cosmetic::ALL
+            .iter()
+            .copied()
+            .filter(|cosmetic| !self.cosmetics.contains(cosmetic) && cosmetic.is_unlocked(self))
+            .collect::<Vec<_>>();
+        self.cosmetics.extend(&unlocked);
+        unlocked
+    }
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Cosmetic {
+    GoldTooth,
+    PointyHat,
+    SlapGloves,
+    ShinyMonocle,
+}
+impl Cosmetic {
+    pub const ALL: &'static [Self] = &[
+        Self::GoldTooth,
+        Self::PointyHat,
+        Self::SlapGloves,
+        Self::ShinyMonocle,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GoldTooth => "Gold Tooth",
+            Self::PointyHat => "Pointy Hat",
+            Self::SlapGloves => "Slap Gloves",
+            Self::ShinyMonocle => "Shiny Monocle",
+        }
+    }
+    pub fn desc(&self) -> &'static str {
+        match self {
+            Self::GoldTooth => "Win an adventure.",
+            Self::PointyHat => "Go on 10 adventures.",
+            Self::SlapGloves => "Win 10 slap fights.",
+            Self::ShinyMonocle => "Bring home a piece of epic loot.",
+        }
+    }
+    pub fn is_unlocked(&self, profile: &Profile) -> bool {
+        match self {
+            Self::GoldTooth => profile.wins >= 1,
+            Self::PointyHat => profile.adventures_played >= 10,
+            Self::SlapGloves => profile.slap_fights_won >= 10,
+            Self::ShinyMonocle => profile.loot[4] >= 1,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::cell::Cell;
+
+// Daily challenges replace live randomness with a seeded xorshift generator so
+// every player gets the same adventure. The generator state lives on the
+// `Adventure` and is loaded into here at the start of every frame.
+thread_local! {
+    static RNG_STATE: Cell<Option<u64>> = Cell::new(None);
+}
+
+/// Seeds the generator, or goes back to live randomness with `None`.
+pub fn seed_rng(state: Option<u64>) {
+    // Xorshift gets stuck on zero
+    RNG_STATE.with(|rng| rng.set(state.map(|state| state.max(1))));
+}
+
+pub fn rng_state() -> Option<u64> {
+    RNG_STATE.with(|rng| rng.get())
+}
+
+/// A random number from the seeded generator when there is one.
+pub fn random_u32() -> u32 {
+    RNG_STATE.with(|rng| match rng.get() {
+        Some(mut x) => {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            rng.set(Some(x));
+            (x >> 32) as u32
+        }
+        None => turbo::sys::rand(),
+    })
+}
+
+/// Days since the unix epoch, used to pick the daily challenge. Asks the turbo
+/// host for the time, since `SystemTime` isn't there in the browser.
+pub fn today() -> u32 {
+    day_of(turbo::sys::millis_since_unix_epoch())
+}
+
+/// The day a unix timestamp in milliseconds falls on, in UTC so everyone
+/// plays the same challenge at the same time.
+pub fn day_of(millis: u64) -> u32 {
+    (millis / 86_400_000) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_start_at_midnight_utc() {
+        assert_eq!(day_of(0), 0);
+        assert_eq!(day_of(86_399_999), 0);
+        assert_eq!(day_of(86_400_000), 1);
+        // 2024-03-01T12:00:00Z
+        assert_eq!(day_of(1_709_294_400_000), 19_783);
+    }
+
+    #[test]
+    fn daily_seed_is_the_same_for_everyone() {
+        let roll = |day: u32| {
+            seed_rng(Some(crate::fnv1a(crate::FNV_OFFSET_BASIS, &day.to_le_bytes())));
+            let rolls = (0..8).map(|_| random_u32()).collect::<Vec<_>>();
+            seed_rng(None);
+            rolls
+        };
+        let day = day_of(1_709_294_400_000);
+        assert_eq!(roll(day), roll(day));
+        assert_ne!(roll(day), roll(day + 1));
+    }
+}
@@ -0,0 +1,208 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use turbo::borsh;
+
+use crate::{HeroKind, Settings};
+
+// Options picked on the Preparing screen. Every setter keeps its option
+// within bounds, so a `Settings` built through them is always valid.
+
+impl Settings {
+    pub const MIN_ROUNDS: u8 = 1;
+    pub const MAX_ROUNDS: u8 = 30;
+    pub const MIN_STARTING_HEALTH: u8 = 1;
+    pub const MAX_STARTING_HEALTH: u8 = 4;
+    pub const MIN_HEROES: usize = 1;
+    pub fn add_rounds(&mut self, amount: i8) {
+        let num_rounds = self.num_rounds as i16 + amount as i16;
+        let num_rounds = num_rounds.clamp(Self::MIN_ROUNDS as i16, Self::MAX_ROUNDS as i16);
+        self.num_rounds = num_rounds as u8;
+    }
+    pub fn add_starting_health(&mut self, amount: i8) {
+        let health = self.starting_health as i16 + amount as i16;
+        let min = Self::MIN_STARTING_HEALTH as i16;
+        let max = Self::MAX_STARTING_HEALTH as i16;
+        self.starting_health = health.clamp(min, max) as u8;
+    }
+    /// Adds or removes a hero from the party, keeping at least one around.
+    pub fn toggle_hero(&mut self, hero: HeroKind) {
+        if self.heroes.contains_key(&hero) {
+            if self.heroes.len() > Self::MIN_HEROES {
+                self.heroes.remove(&hero);
+            }
+        } else {
+            self.heroes.insert(hero, 0);
+        }
+    }
+    pub fn toggle_variant(&mut self, variant: VariantRule) {
+        if let Some(i) = self.variants.iter().position(|v| *v == variant) {
+            self.variants.remove(i);
+        } else {
+            self.variants.push(variant);
+            self.variants.sort();
+        }
+    }
+    pub fn has_variant(&self, variant: VariantRule) -> bool {
+        self.variants.contains(&variant)
+    }
+    pub fn is_valid(&self) -> bool {
+        (Self::MIN_ROUNDS..=Self::MAX_ROUNDS).contains(&self.num_rounds)
+            && (Self::MIN_STARTING_HEALTH..=Self::MAX_STARTING_HEALTH)
+                .contains(&self.starting_health)
+            && self.heroes.len() >= Self::MIN_HEROES
+    }
+    pub fn apply_preset(&mut self, preset: Preset) {
+        self.num_rounds = preset.num_rounds();
+        self.starting_health = preset.starting_health();
+        self.rummage_difficulty = preset.rummage_difficulty();
+        self.heroes = preset.heroes().iter().map(|hero| (*hero, 0)).collect();
+        self.variants = vec![];
+    }
+    /// The preset the options match, if they haven't been customized.
+    pub fn preset(&self) -> Option<Preset> {
+        Preset::ALL.iter().copied().find(|preset| {
+            let mut settings = self.clone();
+            settings.apply_preset(*preset);
+            settings == *self
+        })
+    }
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Preset {
+    Quick,
+    Standard,
+    Marathon,
+}
+impl Preset {
+    pub const ALL: &'static [Self] = &[Self::Quick, Self::Standard, Self::Marathon];
+    pub fn num_rounds(&self) -> u8 {
+        match self {
+            Self::Quick => 5,
+            Self::Standard => 10,
+            Self::Marathon => 20,
+        }
+    }
+    pub fn starting_health(&self) -> u8 {
+        match self {
+            Self::Quick => 2,
+            Self::Standard => 2,
+            Self::Marathon => 3,
+        }
+    }
+    pub fn rummage_difficulty(&self) -> RummageDifficulty {
+        match self {
+            Self::Quick => RummageDifficulty::Easy,
+            Self::Standard => RummageDifficulty::Normal,
+            Self::Marathon => RummageDifficulty::Hard,
+        }
+    }
+    pub fn heroes(&self) -> &'static [HeroKind] {
+        match self {
+            Self::Quick => &[HeroKind::Thief, HeroKind::Wizard, HeroKind::Warrior],
+            Self::Standard => &[
+                HeroKind::Thief,
+                HeroKind::Wizard,
+                HeroKind::Warrior,
+                HeroKind::Merchant,
+            ],
+            Self::Marathon => HeroKind::ALL,
+        }
+    }
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum RummageDifficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+impl RummageDifficulty {
+    pub const ALL: &'static [Self] = &[Self::Easy, Self::Normal, Self::Hard];
+    /// Added to the chance of getting caught rummaging, in percent.
+    pub fn risk(&self) -> i32 {
+        match self {
+            Self::Easy => -10,
+            Self::Normal => 0,
+            Self::Hard => 10,
+        }
+    }
+    pub fn next(&self) -> Self {
+        let i = Self::ALL.iter().position(|d| d == self).unwrap();
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+    pub fn prev(&self) -> Self {
+        let i = Self::ALL.iter().position(|d| d == self).unwrap();
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum VariantRule {
+    // Luck can't be spent on rerolls
+    NoRerolls,
+    // Goblins knocked out by an attack are fired on the spot
+    SuddenDeath,
+}
+impl VariantRule {
+    pub const ALL: &'static [Self] = &[Self::NoRerolls, Self::SuddenDeath];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NoRerolls => "NO REROLLS",
+            Self::SuddenDeath => "SUDDEN DEATH",
+        }
+    }
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum TurnOrderMode {
+    // P1 to P4 every round, earlier goblins start greedier
+    Fixed,
+    // Shuffled every round, everyone starts a little greedy
+    Shuffle,
+    // Reversed every round, the handicap is halved
+    Snake,
+    // Poorest goblin first every round, the richest gets greedier
+    PoorestFirst,
+}
+impl TurnOrderMode {
+    pub const ALL: &'static [Self] = &[
+        Self::Fixed,
+        Self::Shuffle,
+        Self::Snake,
+        Self::PoorestFirst,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fixed => "FIXED",
+            Self::Shuffle => "SHUFFLE",
+            Self::Snake => "SNAKE",
+            Self::PoorestFirst => "POOREST",
+        }
+    }
+    /// Greed the goblin in the `i`th seat starts with.
+    pub fn starting_greed(&self, i: u8) -> u8 {
+        let greed = 4u8.saturating_sub(i);
+        match self {
+            Self::Fixed => greed,
+            Self::Shuffle => 2,
+            Self::Snake => greed / 2,
+            Self::PoorestFirst => 0,
+        }
+    }
+    pub fn next(&self) -> Self {
+        let i = Self::ALL.iter().position(|m| m == self).unwrap();
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+    pub fn prev(&self) -> Self {
+        let i = Self::ALL.iter().position(|m| m == self).unwrap();
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
@@ -1,61 +1,136 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Deserialize;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
 use turbo::{borsh, solana::solana_sdk};
 
-use crate::{EventLocationData, ALL_EVENT_LOCATION_DATA};
+use crate::{
+    content, fnv1a, random_u32, rng_state, seed_rng, ContentPack, DropTable, EventLocationData,
+    EventScenarioAction, HeroData, LootData, RummageDifficulty, TraitData, TurnOrderMode,
+    VariantRule, FNV_OFFSET_BASIS, GOBLIN_NAME_PREFIXES, GOBLIN_NAME_SUFFIXES, HERO_DATA,
+    LOOT_CATALOG, TRAIT_DATA,
+};
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Adventure {
     pub creator: Pubkey,
     pub save_slot: u8,
     pub state: AdventureState,
+    // Notable moments, drained by the frame loop to unlock achievements
+    pub events: Vec<AdventureEvent>,
+    // Running hash of every state the adventure has been in. Two runs with
+    // the same hash played out the same way
+    pub replay_hash: u64,
+    // The day of the daily challenge being played, if any
+    pub challenge: Option<u32>,
+    // Seeded generator state, see `rng.rs`
+    pub rng: Option<u64>,
 }
 impl Adventure {
-    pub fn new(_p1_pubkey: Pubkey) -> Self {
+    pub const DAILY_PARTY: &'static [Player] = &[Player::P1, Player::P2];
+    pub const DAILY_NUM_ROUNDS: u8 = 5;
+    pub const DAILY_NUM_HEROES: usize = 3;
+    pub fn new(p1_pubkey: Pubkey) -> Self {
         let goblins = HashMap::from([(Player::P1, Goblin::new())]);
-        let settings = Settings::new();
+        let mut settings = Settings::new();
+        settings.goblin_owners.insert(p1_pubkey, Player::P1);
         Self {
             creator: Pubkey::default(),
             save_slot: 0,
             state: AdventureState::Preparing(goblins, settings),
+            events: vec![],
+            replay_hash: FNV_OFFSET_BASIS,
+            challenge: None,
+            rng: None,
+        }
+    }
+    /// Starts the daily challenge for a day. Everyone playing the same day
+    /// gets the same party, heroes and dice.
+    pub fn daily(p1_pubkey: Pubkey, day: u32) -> Self {
+        seed_rng(Some(fnv1a(FNV_OFFSET_BASIS, &day.to_le_bytes())));
+        let mut adventure = Self::new(p1_pubkey);
+        if let AdventureState::Preparing(goblins, settings) = &mut adventure.state {
+            for player in Self::DAILY_PARTY {
+                let trait_kind = TraitKind::ALL[random_u32() as usize % TraitKind::ALL.len()];
+                goblins.insert(*player, Goblin::with_trait(Some(trait_kind)));
+            }
+            settings.num_rounds = Self::DAILY_NUM_ROUNDS;
+            let mut heroes = HeroKind::ALL.to_vec();
+            while heroes.len() > Self::DAILY_NUM_HEROES {
+                heroes.remove(random_u32() as usize % heroes.len());
+            }
+            settings.heroes = heroes.into_iter().map(|hero| (hero, 0)).collect();
+        }
+        adventure.challenge = Some(day);
+        let _ = adventure.start_adventure();
+        adventure.update_replay_hash();
+        adventure.rng = rng_state();
+        adventure
+    }
+    /// Folds the current state into the replay hash. Called after every
+    /// transition.
+    pub fn update_replay_hash(&mut self) {
+        let mut bytes = vec![];
+        if self.state.serialize(&mut bytes).is_ok() {
+            self.replay_hash = fnv1a(self.replay_hash, &bytes);
         }
     }
     pub fn start_adventure(&mut self) -> Result<(), ()> {
         if let AdventureState::Preparing(goblins, settings) = &self.state {
-            let turn = Turn::new(*settings.goblin_order.get(&0).unwrap());
+            if !settings.is_valid() {
+                return Err(());
+            }
             let mut goblins = goblins.clone();
             let mut settings = settings.clone();
             settings.update_goblin_order(&mut goblins);
+            settings.update_goblin_owners(&goblins);
+            let turn = Turn::new(*settings.goblin_order.get(&0).unwrap());
+            for goblin in goblins.values_mut() {
+                let bonus = goblin.trait_data().map_or(0, |data| data.health);
+                goblin.health = settings.starting_health + bonus;
+            }
             turbo::println!("{:#?}\n{:#?}", goblins, settings);
             self.state = AdventureState::Started(
                 goblins,
                 settings,
                 turn,
                 AdventurePhase::Camp(CampPhase::new()),
+                Dungeon::new(content().locations.len()),
+                PartySack::new(),
             );
             return Ok(());
         }
         return Err(());
     }
     pub fn rummage_for_loot(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(_goblins, _settings, _turn, phase) = &mut self.state {
+        if let AdventureState::Started(goblins, settings, turn, phase, _dungeon, sack) = &mut self.state {
             if let AdventurePhase::Camp(camp_phase) = phase {
                 if camp_phase.rummage_result == None {
-                    let possible_loot = [
-                        Rarity::Common,
-                        Rarity::Uncommon,
-                        Rarity::Rare,
-                        Rarity::Legendary,
-                        Rarity::Epic,
-                    ];
-                    camp_phase.rummage_result = Some(RummageResult::Success {
-                        loot: Loot {
-                            rarity: possible_loot
-                                [turbo::sys::rand() as usize % possible_loot.len()],
-                        },
-                        did_take: None,
-                    });
+                    // The heroes keep a closer eye on a sack that keeps shrinking, and on greedy goblins
+                    let goblin = &goblins[&turn.player];
+                    let risk = sack.suspicion() + goblin.greed_bonus() as u32 * Goblin::GREED_DETECTION;
+                    // Some goblins are quieter about it than others
+                    let data = goblin.trait_data();
+                    let risk = risk as i32 + settings.rummage_difficulty.risk();
+                    let risk = (risk + data.map_or(0, |data| data.rummage_risk)).max(0) as u32;
+                    let is_caught = random_u32() % 100 < risk;
+                    // Lucky goblins get a few rummages and keep the best find
+                    let tries = goblin.luck as usize + data.map_or(0, |data| data.rummage_tries) as usize;
+                    #[rustfmt::skip]
+                    let loot = (0..=tries).filter_map(|_| sack.peek()).max_by_key(Loot::value);
+                    camp_phase.rummage_result = match loot {
+                        // Nothing to steal means nothing to get caught stealing
+                        None => Some(RummageResult::Empty),
+                        Some(loot) if !is_caught => Some(RummageResult::Success {
+                            loot,
+                            did_take: None,
+                        }),
+                        Some(_) => {
+                            goblins.get_mut(&turn.player).unwrap().times_caught += 1;
+                            settings.raise_suspicion(HeroKind::RUMMAGE_FAIL_SUSPICION);
+                            Some(RummageResult::Fail)
+                        }
+                    };
                     return Ok(());
                 }
             }
@@ -63,17 +138,21 @@ impl Adventure {
         return Err(());
     }
     pub fn rummage_take_loot(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(ref mut goblins, _settings, turn, phase) = &mut self.state {
+        if let AdventureState::Started(ref mut goblins, _settings, turn, phase, _dungeon, sack) = &mut self.state {
             if let AdventurePhase::Camp(camp_phase) = phase {
                 match &mut camp_phase.rummage_result {
                     Some(RummageResult::Success {
                         loot,
                         ref mut did_take,
                     }) => {
-                        if did_take.is_none() {
+                        // The loot stays in the sack if there's no way to carry it
+                        if did_take.is_none() && goblins[&turn.player].can_carry() {
+                            sack.take(loot).ok_or(())?;
                             let goblin = goblins.get_mut(&turn.player).unwrap();
-                            goblin.greed += 1;
-                            goblin.loot.push(loot.clone());
+                            goblin.add_greed(1);
+                            goblin.add_loot(loot.clone()).map_err(|_| ())?;
+                            #[rustfmt::skip]
+                            self.events.push(AdventureEvent::LootFound { player: turn.player, rarity: loot.rarity });
                             *did_take = Some(true);
                             return Ok(());
                         }
@@ -85,7 +164,7 @@ impl Adventure {
         return Err(());
     }
     pub fn rummage_leave_loot(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(ref mut goblins, _settings, turn, phase) = &mut self.state {
+        if let AdventureState::Started(ref mut goblins, _settings, turn, phase, _dungeon, _sack) = &mut self.state {
             if let AdventurePhase::Camp(camp_phase) = phase {
                 match &mut camp_phase.rummage_result {
                     Some(RummageResult::Success {
@@ -108,45 +187,68 @@ impl Adventure {
         return Err(());
     }
     pub fn event_start(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(_goblins, _settings, _turn, phase) = &mut self.state {
+        if let AdventureState::Started(_goblins, _settings, _turn, phase, dungeon, _sack) = &mut self.state {
             if let AdventurePhase::Camp(_camp_phase) = phase {
-                let locations = ALL_EVENT_LOCATION_DATA;
-                let location_index = turbo::sys::rand() as usize % locations.len();
-                let location = &locations[location_index];
-                let scenarios = location.scenarios;
-                let scenarios_index = turbo::sys::rand() as usize % scenarios.len();
-                *phase = AdventurePhase::Event(EventPhase {
-                    location: location_index,
-                    scenario: scenarios_index,
-                    outcome: None,
-                });
+                // Coming back from camp, the party wanders down a random path
+                let num_paths = dungeon.paths().len();
+                let path = random_u32() as usize % num_paths;
+                *phase = AdventurePhase::Event(dungeon.advance(path).ok_or(())?);
                 return Ok(());
             }
         }
         return Err(());
     }
-    pub fn keep_going(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(_goblins, _settings, turn, phase) = &mut self.state {
+    pub fn keep_going(&mut self, path: usize) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, turn, phase, dungeon, _sack) = &mut self.state {
             if let AdventurePhase::Event(_event_phase) = phase {
+                // Goblins at max greed can't help themselves
+                if goblins[&turn.player].greed >= Goblin::MAX_GREED {
+                    return self.greedy_fit();
+                }
+                *phase = AdventurePhase::Event(dungeon.advance(path).ok_or(())?);
                 turn.num_events += 1;
-                let locations = ALL_EVENT_LOCATION_DATA;
-                let location_index = turbo::sys::rand() as usize % locations.len();
-                let location = &locations[location_index];
-                let scenarios = location.scenarios;
-                let scenarios_index = turbo::sys::rand() as usize % scenarios.len();
-                *phase = AdventurePhase::Event(EventPhase {
-                    location: location_index,
-                    scenario: scenarios_index,
-                    outcome: None,
-                });
+                return Ok(());
+            }
+        }
+        return Err(());
+    }
+    /// Ends the turn in a tantrum that empties the pot into the party sack
+    /// and puts every hero on edge.
+    pub fn greedy_fit(&mut self) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, settings, turn, phase, _dungeon, sack) = &mut self.state {
+            if let AdventurePhase::Event(_event_phase) = phase {
+                let player = turn.player;
+                // The heroes happily scoop up whatever gets thrown around
+                for loot in turn.pot.drain(..) {
+                    sack.deposit(loot);
+                }
+                let goblin = goblins.get_mut(&player).unwrap();
+                goblin.greed = Goblin::GREEDY;
+                settings.raise_suspicion(HeroKind::GREEDY_FIT_SUSPICION);
+                self.take_a_break()?;
+                if let AdventureState::Started(_, _, _, AdventurePhase::Camp(camp_phase), _, _) = &mut self.state {
+                    camp_phase.greedy_fit = Some(player);
+                }
                 return Ok(());
             }
         }
         return Err(());
     }
     pub fn take_a_break(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(_goblins, settings, turn, phase) = &mut self.state {
+        if let AdventureState::Started(goblins, settings, turn, phase, _dungeon, sack) = &mut self.state {
             if let AdventurePhase::Event(_event_phase) = phase {
+                // Taking a break banks the pot, the heroes scoop up whatever doesn't fit
+                let goblin = goblins.get_mut(&turn.player).unwrap();
+                for loot in turn.pot.drain(..) {
+                    if let Err(loot) = goblin.add_loot(loot) {
+                        sack.deposit(loot);
+                    }
+                }
+                // The heroes grow wary of greedy goblins and act once they've seen enough
+                let greed = goblin.greed as usize + goblin.greed_bonus() as usize;
+                settings.calm_heroes(HeroKind::SUSPICION_DECAY);
+                settings.raise_suspicion(greed / HeroKind::GREED_PER_SUSPICION);
+                let hero_action = settings.review_goblin(turn.player, goblins, sack);
                 let mut curr_player_index = settings
                     .goblin_order
                     .iter()
@@ -157,111 +259,154 @@ impl Adventure {
                         return None;
                     })
                     .unwrap_or(0);
-                curr_player_index += 1;
-                curr_player_index %= settings.goblin_order.len() as u8;
+                // Fired goblins don't get any more turns
+                let num_goblins = settings.goblin_order.len() as u8;
+                let mut is_new_round = false;
+                for _ in 0..num_goblins {
+                    curr_player_index += 1;
+                    curr_player_index %= num_goblins;
+                    is_new_round |= curr_player_index == 0;
+                    if !goblins[&settings.goblin_order[&curr_player_index]].is_fired {
+                        break;
+                    }
+                }
+                // Some turn orders get shaken up between rounds
+                if is_new_round {
+                    settings.start_round(goblins);
+                    #[rustfmt::skip]
+                    let i = (0..num_goblins).find(|i| !goblins[&settings.goblin_order[i]].is_fired);
+                    curr_player_index = i.unwrap_or(0);
+                }
                 turn.player = settings.goblin_order[&curr_player_index];
-                *phase = AdventurePhase::Camp(CampPhase::new());
+                turn.num_events = 0;
+                turn.rerolls = 0;
+                let mut camp_phase = CampPhase::new();
+                camp_phase.hero_action = hero_action;
+                *phase = AdventurePhase::Camp(camp_phase);
+                // A round is over once every goblin has had a turn
+                if is_new_round {
+                    turn.round += 1;
+                }
+                if turn.round >= settings.num_rounds || goblins[&turn.player].is_fired {
+                    return self.complete();
+                }
                 return Ok(());
             }
         }
         return Err(());
     }
+    /// Ends the adventure, settling the party sack.
+    pub fn complete(&mut self) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, settings, _turn, _phase, _dungeon, sack) = &self.state {
+            let winner = sack.winner(goblins);
+            let mut players = goblins.keys().copied().collect::<Vec<_>>();
+            players.sort();
+            for player in players {
+                let won = winner == Some(player);
+                self.events.push(AdventureEvent::Completed { player, won });
+            }
+            self.state = AdventureState::Complete(goblins.clone(), settings.clone(), sack.clone());
+            return Ok(());
+        }
+        return Err(());
+    }
     pub fn event_make_choice(&mut self, action_index: usize) -> Result<(), ()> {
-        if let AdventureState::Started(goblins, _settings, turn, phase) = &mut self.state {
+        if let AdventureState::Started(goblins, _settings, turn, phase, _dungeon, _sack) = &mut self.state {
             if let AdventurePhase::Event(event_phase) = phase {
-                let data = EventLocationData::get(event_phase.location);
+                let content = content();
+                let data = content.location(event_phase.location);
                 let scenario = &data.scenarios[event_phase.scenario];
-                let outcomes = scenario.actions[action_index].outcomes;
-                let effect = turbo::sys::rand() as usize % outcomes.len();
+                let action = scenario.actions.get(action_index).ok_or(())?;
                 let goblin = goblins.get_mut(&turn.player).unwrap();
-                goblin.greed += 1;
-                event_phase.outcome = Some(EventPhaseOutcome {
-                    choice: action_index,
-                    effect: effect,
-                    accepted: false,
-                });
+                if !action.is_available(goblin) {
+                    return Err(());
+                }
+                // Items are used up by the action that needs them
+                if let Some(item) = action.item {
+                    let i = goblin.items.iter().position(|it| *it == item).ok_or(())?;
+                    goblin.items.remove(i);
+                }
+                goblin.add_greed(1);
+                let danger = turn.danger(data);
+                event_phase.outcome = Some(roll_outcome(action_index, action, danger, goblin));
                 return Ok(());
             }
         }
         return Err(());
     }
+    /// Spends a point of luck to reroll a bad outcome before it's confirmed.
+    pub fn event_reroll(&mut self) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, settings, turn, phase, _dungeon, _sack) = &mut self.state {
+            if let AdventurePhase::Event(event_phase) = phase {
+                if let Some(outcome) = &event_phase.outcome {
+                    let content = content();
+                    let data = content.location(event_phase.location);
+                    let action = &data.scenarios[event_phase.scenario].actions[outcome.choice];
+                    let goblin = goblins.get_mut(&turn.player).unwrap();
+                    let is_bad = !action.outcomes[outcome.effect].effect.is_good() && !outcome.is_protected;
+                    if outcome.accepted || !is_bad || !turn.can_reroll(goblin, settings) {
+                        return Err(());
+                    }
+                    goblin.luck -= 1;
+                    turn.rerolls += 1;
+                    let danger = turn.danger(data);
+                    event_phase.outcome = Some(roll_outcome(outcome.choice, action, danger, goblin));
+                    return Ok(());
+                }
+            }
+        }
+        return Err(());
+    }
     pub fn event_handle_outcome(&mut self) -> Result<(), ()> {
-        if let AdventureState::Started(goblins, settings, turn, phase) = &mut self.state {
+        if let AdventureState::Started(goblins, settings, turn, phase, dungeon, sack) = &mut self.state {
             if let AdventurePhase::Event(event_phase) = phase {
                 // TODO: apply side-effects such as gaining loot, getting attacked, etc
                 if let Some(ref mut outcome) = event_phase.outcome {
-                    let data = EventLocationData::get(event_phase.location);
+                    let content = content();
+                    let data = content.location(event_phase.location);
                     let outcomes =
-                        data.scenarios[event_phase.scenario].actions[outcome.choice].outcomes;
+                        &data.scenarios[event_phase.scenario].actions[outcome.choice].outcomes;
                     let result = outcomes[outcome.effect % outcomes.len()].effect;
+                    #[rustfmt::skip]
+                    let result = if outcome.is_protected { EventResult::OK } else { result };
+                    // Loot found deeper in the dungeon or further into a streak tends to be rarer
+                    let drops = DropTable::for_location(data).with(DropTable::for_outcome(result));
+                    // Greedy goblins have a nose for the good stuff
+                    let goblin = &goblins[&turn.player];
+                    let luck = goblin.luck + goblin.greed_bonus();
+                    let depth = dungeon.depth() as u32 + turn.num_events as u32;
+                    let roll_loot = || Loot::new(drops.roll(luck, depth));
+                    // Whatever happens to the goblin, the heroes haul their share into the sack
+                    let haul = DropTable::for_location(data).roll(0, depth);
+                    sack.deposit(Loot::new(haul));
+                    // A bad outcome spills everything found since the last break
+                    let spilled = if result.is_good() {
+                        vec![]
+                    } else {
+                        std::mem::take(&mut turn.pot)
+                    };
+                    let pot_len = turn.pot.len();
                     match result {
                         EventResult::GetLoot => {
-                            let possible_loot = [
-                                Rarity::Common,
-                                Rarity::Uncommon,
-                                Rarity::Rare,
-                                Rarity::Legendary,
-                                Rarity::Epic,
-                            ];
-                            let loot = Loot {
-                                rarity: possible_loot
-                                    [turbo::sys::rand() as usize % possible_loot.len()],
-                            };
-                            let goblin = goblins.get_mut(&turn.player).unwrap();
-                            // goblin.greed += 1;
-                            goblin.loot.push(loot.clone());
-                            //
+                            turn.pot.push(roll_loot());
                         }
                         EventResult::GetItem => {
-                            //
-                            let possible_loot = [
-                                Rarity::Common,
-                                Rarity::Uncommon,
-                                Rarity::Rare,
-                                Rarity::Legendary,
-                                Rarity::Epic,
-                            ];
-                            let loot = Loot {
-                                rarity: possible_loot
-                                    [turbo::sys::rand() as usize % possible_loot.len()],
-                            };
-                            let goblin = goblins.get_mut(&turn.player).unwrap();
-                            // goblin.greed += 1;
-                            goblin.loot.push(loot.clone());
-                        }
-                        EventResult::StealLoot => {
-                            //
-                            let possible_loot = [
-                                Rarity::Common,
-                                Rarity::Uncommon,
-                                Rarity::Rare,
-                                Rarity::Legendary,
-                                Rarity::Epic,
-                            ];
-                            let loot = Loot {
-                                rarity: possible_loot
-                                    [turbo::sys::rand() as usize % possible_loot.len()],
-                            };
+                            // Goblins with a free item slot find something useful
                             let goblin = goblins.get_mut(&turn.player).unwrap();
-                            // goblin.greed += 1;
-                            goblin.loot.push(loot.clone());
+                            if goblin.items.len() < Goblin::MAX_ITEMS_LEN {
+                                let item = ItemKind::ALL[random_u32() as usize % ItemKind::ALL.len()];
+                                goblin.items.push(item);
+                            } else {
+                                turn.pot.push(roll_loot());
+                            }
                         }
-                        EventResult::StealItem => {
-                            //
-                            let possible_loot = [
-                                Rarity::Common,
-                                Rarity::Uncommon,
-                                Rarity::Rare,
-                                Rarity::Legendary,
-                                Rarity::Epic,
-                            ];
-                            let loot = Loot {
-                                rarity: possible_loot
-                                    [turbo::sys::rand() as usize % possible_loot.len()],
-                            };
-                            let goblin = goblins.get_mut(&turn.player).unwrap();
-                            // goblin.greed += 1;
-                            goblin.loot.push(loot.clone());
+                        EventResult::StealLoot | EventResult::StealItem => {
+                            turn.pot.push(roll_loot());
+                            // Blabbermouths can't help bragging about it
+                            let extra = goblins[&turn.player].trait_data().map_or(0, |data| data.theft_suspicion);
+                            if let Some(hero) = settings.random_hero() {
+                                settings.raise_hero_suspicion(hero, HeroKind::THEFT_SUSPICION + extra);
+                            }
                         }
                         EventResult::Heal => {
                             let goblin = goblins.get_mut(&turn.player).unwrap();
@@ -289,25 +434,38 @@ impl Adventure {
                             }
                         }
                         EventResult::LoseLoot => {
-                            let goblin = goblins.get_mut(&turn.player).unwrap();
-                            let _ = goblin.loot.pop();
+                            // Only banked loot is at risk once the pot is gone
+                            if spilled.is_empty() {
+                                let goblin = goblins.get_mut(&turn.player).unwrap();
+                                let _ = goblin.loot.pop();
+                            }
                         }
                         EventResult::LoseItem => {
-                            //
                             let goblin = goblins.get_mut(&turn.player).unwrap();
-                            let _ = goblin.loot.pop();
+                            if goblin.items.pop().is_none() {
+                                let _ = goblin.loot.pop();
+                            }
                         }
                         EventResult::LootGotStolen => {
-                            let loot = {
+                            // The thief makes off with the pot, or a banked piece without one
+                            let loot = if spilled.is_empty() {
                                 let goblin = goblins.get_mut(&turn.player).unwrap();
-                                goblin.loot.pop().clone()
+                                goblin.loot.pop().into_iter().collect()
+                            } else {
+                                spilled
                             };
-                            if let Some(loot) = loot {
-                                let i = turn.player.index() + 1 % settings.goblin_order.len();
-                                let i = i as u8;
-                                let next_player = settings.goblin_order[&i];
-                                let goblin = goblins.get_mut(&next_player).unwrap();
-                                goblin.loot.push(loot);
+                            // The thief is whoever goes next. Anything too much for them to
+                            // carry, or everything without another goblin around, is left for
+                            // the heroes
+                            let thief = settings.next_player(turn.player, goblins);
+                            for loot in loot {
+                                let loot = match thief.and_then(|p| goblins.get_mut(&p)) {
+                                    Some(goblin) => goblin.add_loot(loot),
+                                    None => Err(loot),
+                                };
+                                if let Err(loot) = loot {
+                                    sack.deposit(loot);
+                                }
                             }
                         }
                         EventResult::ItemGotStolen => {
@@ -316,18 +474,52 @@ impl Adventure {
                             let _ = goblin.loot.pop();
                         }
                         EventResult::SlapFight => {
-                            //
+                            // Another goblin picks a fight, the loser hands over a piece of loot
+                            let mut opponents = goblins
+                                .iter()
+                                .filter(|(p, goblin)| **p != turn.player && !goblin.is_fired)
+                                .map(|(p, _)| *p)
+                                .collect::<Vec<_>>();
+                            opponents.sort();
+                            if !opponents.is_empty() {
+                                let opponent = opponents[random_u32() as usize % opponents.len()];
+                                let slap = |goblin: &Goblin| {
+                                    let bonus = goblin.trait_data().map_or(0, |data| data.slap);
+                                    random_u32() % 6 + 1 + goblin.health as u32 + bonus
+                                };
+                                let won = slap(&goblins[&turn.player]) >= slap(&goblins[&opponent]);
+                                let (winner, loser) = if won { (turn.player, opponent) } else { (opponent, turn.player) };
+                                goblins.get_mut(&winner).unwrap().slap_fights_won += 1;
+                                // The loser hangs on to it if the winner's hands are full
+                                if let Some(loot) = goblins.get_mut(&loser).unwrap().loot.pop() {
+                                    if let Err(loot) = goblins.get_mut(&winner).unwrap().add_loot(loot) {
+                                        goblins.get_mut(&loser).unwrap().loot.push(loot);
+                                    }
+                                }
+                                outcome.slap_fight = Some(SlapFight { opponent, won });
+                            }
                         }
                         EventResult::GetAttacked => {
                             let goblin = goblins.get_mut(&turn.player).unwrap();
                             if goblin.health > 0 {
                                 goblin.health -= 1;
                             }
+                            if goblin.health == 0 && settings.has_variant(VariantRule::SuddenDeath) {
+                                goblin.is_fired = true;
+                            }
                         }
                         EventResult::OK => {
                             //
                         }
                     };
+                    for loot in &turn.pot[pot_len..] {
+                        #[rustfmt::skip]
+                        self.events.push(AdventureEvent::LootFound { player: turn.player, rarity: loot.rarity });
+                    }
+                    if result.is_good() {
+                        #[rustfmt::skip]
+                        self.events.push(AdventureEvent::EventSurvived { player: turn.player, streak: turn.num_events + 1 });
+                    }
                     outcome.accepted = true;
                     return Ok(());
                 }
@@ -335,6 +527,169 @@ impl Adventure {
         }
         return Err(());
     }
+    pub fn drop_loot(&mut self, i: usize) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, turn, _phase, _dungeon, _sack) = &mut self.state {
+            let goblin = goblins.get_mut(&turn.player).unwrap();
+            if i < goblin.loot.len() {
+                goblin.loot.remove(i);
+                return Ok(());
+            }
+        }
+        return Err(());
+    }
+    pub fn give_loot(&mut self, i: usize, to: Player) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, turn, _phase, _dungeon, _sack) = &mut self.state {
+            // Gifts only go to goblins with room in their bag
+            if to != turn.player && goblins.get(&to).map_or(false, Goblin::has_room) {
+                let goblin = goblins.get_mut(&turn.player).unwrap();
+                if i < goblin.loot.len() {
+                    let loot = goblin.loot.remove(i);
+                    let goblin = goblins.get_mut(&to).unwrap();
+                    goblin.add_loot(loot).map_err(|_| ())?;
+                    return Ok(());
+                }
+            }
+        }
+        return Err(());
+    }
+    pub fn bribe_hero(&mut self, i: usize, hero: HeroKind) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, settings, turn, phase, _dungeon, _sack) = &mut self.state {
+            if let AdventurePhase::Camp(camp_phase) = phase {
+                let goblin = goblins.get_mut(&turn.player).unwrap();
+                if camp_phase.bribe_result == None && i < goblin.loot.len() && settings.heroes.contains_key(&hero) {
+                    let data = hero.data();
+                    // Heroes turn their noses up at loot they don't care for
+                    if !data.accepts(&goblin.loot[i]) {
+                        settings.raise_hero_suspicion(hero, HeroKind::REFUSED_BRIBE_SUSPICION);
+                        camp_phase.bribe_result = Some(BribeResult {
+                            hero,
+                            reward: None,
+                            confirmed: false,
+                        });
+                        return Ok(());
+                    }
+                    // The hero pockets the loot and slips the goblin something in return
+                    goblin.loot.remove(i);
+                    settings.lower_suspicion(hero, HeroKind::BRIBE_SUSPICION);
+                    let reward = match data.reward {
+                        BribeReward::AnyItem => {
+                            BribeReward::Item(ItemKind::ALL[random_u32() as usize % ItemKind::ALL.len()])
+                        }
+                        reward => reward,
+                    };
+                    match reward {
+                        BribeReward::Item(item) => {
+                            // Goblins with full pockets trade their oldest item away for it
+                            if goblin.items.len() >= Goblin::MAX_ITEMS_LEN {
+                                goblin.items.remove(0);
+                            }
+                            goblin.items.push(item);
+                        }
+                        BribeReward::Protection => {
+                            goblin.is_protected = true;
+                        }
+                        BribeReward::AnyItem => {}
+                    }
+                    camp_phase.bribe_result = Some(BribeResult {
+                        hero,
+                        reward: Some(reward),
+                        confirmed: false,
+                    });
+                    return Ok(());
+                }
+            }
+        }
+        return Err(());
+    }
+    pub fn use_item(&mut self, i: usize) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, turn, _phase, _dungeon, _sack) = &mut self.state {
+            let goblin = goblins.get_mut(&turn.player).unwrap();
+            match goblin.items.get(i) {
+                Some(ItemKind::LuckCharm) => {
+                    goblin.items.remove(i);
+                    goblin.luck += 1;
+                    return Ok(());
+                }
+                Some(ItemKind::BiggerBag) if goblin.bag_size < Goblin::MAX_LOOT_LEN as u8 => {
+                    goblin.items.remove(i);
+                    goblin.bag_size += Goblin::BAG_UPGRADE_LEN;
+                    goblin.bag_size = goblin.bag_size.min(Goblin::MAX_LOOT_LEN as u8);
+                    return Ok(());
+                }
+                // Other items are used up by the scenario actions that need them
+                _ => {}
+            }
+        }
+        return Err(());
+    }
+    /// Swaps a piece of loot in the bag for the first piece that didn't fit.
+    /// The heroes pick up the piece swapped out and put it in the sack.
+    pub fn swap_overflow(&mut self, player: Player, i: usize) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, _turn, _phase, _dungeon, sack) = &mut self.state {
+            let goblin = goblins.get_mut(&player).ok_or(())?;
+            if i < goblin.loot.len() && !goblin.overflow.is_empty() {
+                let loot = std::mem::replace(&mut goblin.loot[i], goblin.overflow.remove(0));
+                sack.deposit(loot);
+                return Ok(());
+            }
+        }
+        return Err(());
+    }
+    /// Leaves the first piece of loot that didn't fit in the bag for the
+    /// heroes to put in the sack.
+    pub fn leave_overflow(&mut self, player: Player) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, _turn, _phase, _dungeon, sack) = &mut self.state {
+            let goblin = goblins.get_mut(&player).ok_or(())?;
+            if !goblin.overflow.is_empty() {
+                sack.deposit(goblin.overflow.remove(0));
+                return Ok(());
+            }
+        }
+        return Err(());
+    }
+    pub fn drop_item(&mut self, i: usize) -> Result<(), ()> {
+        if let AdventureState::Started(goblins, _settings, turn, _phase, _dungeon, _sack) = &mut self.state {
+            let goblin = goblins.get_mut(&turn.player).unwrap();
+            if i < goblin.items.len() {
+                goblin.items.remove(i);
+                return Ok(());
+            }
+        }
+        return Err(());
+    }
+    /// Points an in-progress event and the dungeon map at the same locations
+    /// and scenario in a reloaded content pack, matching them by name.
+    /// Anything that no longer exists is clamped to a valid index.
+    pub fn remap_content(&mut self, old: &ContentPack, new: &ContentPack) {
+        let remap_location = |i: usize| {
+            let name = &old.location(i).name;
+            new.locations
+                .iter()
+                .position(|l| l.name == *name)
+                .unwrap_or(i % new.locations.len())
+        };
+        if let AdventureState::Started(_goblins, _settings, _turn, phase, dungeon, _sack) = &mut self.state {
+            for node in &mut dungeon.nodes {
+                node.location = remap_location(node.location);
+            }
+            if let AdventurePhase::Event(event_phase) = phase {
+                let location = old.location(event_phase.location);
+                let scenario = &location.scenarios[event_phase.scenario % location.scenarios.len()];
+                event_phase.location = remap_location(event_phase.location);
+                let location = new.location(event_phase.location);
+                event_phase.scenario = location
+                    .scenarios
+                    .iter()
+                    .position(|s| s.name == scenario.name)
+                    .unwrap_or(event_phase.scenario % location.scenarios.len());
+                if let Some(ref mut outcome) = event_phase.outcome {
+                    let actions = &location.scenarios[event_phase.scenario].actions;
+                    outcome.choice = outcome.choice.min(actions.len() - 1);
+                    outcome.effect %= actions[outcome.choice].outcomes.len();
+                }
+            }
+        }
+    }
 }
 
 pub type GoblinMap = HashMap<Player, Goblin>;
@@ -346,8 +701,8 @@ pub type GoblinOwners = HashMap<Pubkey, Player>;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub enum AdventureState {
     Preparing(GoblinMap, Settings),
-    Started(GoblinMap, Settings, Turn, AdventurePhase),
-    Complete(GoblinMap, Settings),
+    Started(GoblinMap, Settings, Turn, AdventurePhase, Dungeon, PartySack),
+    Complete(GoblinMap, Settings, PartySack),
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
@@ -356,6 +711,11 @@ pub struct Settings {
     pub goblin_order: GoblinOrder,
     pub goblin_owners: GoblinOwners,
     pub heroes: HashMap<HeroKind, usize>,
+    pub turn_order_mode: TurnOrderMode,
+    // Health every goblin starts with, before traits
+    pub starting_health: u8,
+    pub rummage_difficulty: RummageDifficulty,
+    pub variants: Vec<VariantRule>,
 }
 impl Settings {
     pub fn new() -> Self {
@@ -369,40 +729,204 @@ impl Settings {
                 (HeroKind::Warrior, 0),
                 (HeroKind::Merchant, 0),
             ]),
+            turn_order_mode: TurnOrderMode::Fixed,
+            starting_health: 2,
+            rummage_difficulty: RummageDifficulty::Normal,
+            variants: vec![],
+        }
+    }
+    /// The heroes in the party, in a stable order.
+    pub fn hero_party(&self) -> Vec<HeroKind> {
+        let mut heroes = self.heroes.keys().copied().collect::<Vec<_>>();
+        heroes.sort();
+        heroes
+    }
+    /// A random hero from the party, if there are any.
+    pub fn random_hero(&self) -> Option<HeroKind> {
+        let heroes = self.hero_party();
+        if heroes.is_empty() {
+            return None;
+        }
+        Some(heroes[random_u32() as usize % heroes.len()])
+    }
+    pub fn raise_hero_suspicion(&mut self, hero: HeroKind, amount: usize) {
+        if let Some(suspicion) = self.heroes.get_mut(&hero) {
+            *suspicion = (*suspicion + amount).min(HeroKind::MAX_SUSPICION);
+        }
+    }
+    pub fn lower_suspicion(&mut self, hero: HeroKind, amount: usize) {
+        if let Some(suspicion) = self.heroes.get_mut(&hero) {
+            *suspicion = suspicion.saturating_sub(amount);
+        }
+    }
+    pub fn raise_suspicion(&mut self, amount: usize) {
+        for hero in self.hero_party() {
+            self.raise_hero_suspicion(hero, amount);
+        }
+    }
+    pub fn calm_heroes(&mut self, amount: usize) {
+        for hero in self.hero_party() {
+            self.lower_suspicion(hero, amount);
+        }
+    }
+    /// Lets the most suspicious hero act on a goblin once their suspicion
+    /// crosses a threshold, after which they calm down.
+    pub fn review_goblin(
+        &mut self,
+        player: Player,
+        goblins: &mut GoblinMap,
+        sack: &mut PartySack,
+    ) -> Option<HeroAction> {
+        #[rustfmt::skip]
+        let hero = self.hero_party().into_iter().max_by_key(|hero| self.heroes[hero])?;
+        let kind = HeroActionKind::for_suspicion(self.heroes[&hero])?;
+        let goblin = goblins.get_mut(&player).unwrap();
+        match kind {
+            HeroActionKind::Search => {
+                // Anything that looks like it came from the sack goes back in
+                let i = (0..goblin.loot.len()).max_by_key(|i| goblin.loot[*i].value());
+                if let Some(i) = i {
+                    sack.recover(goblin.loot.remove(i));
+                }
+            }
+            HeroActionKind::Attack => {
+                goblin.health = goblin.health.saturating_sub(1);
+                goblin.is_fired = goblin.health == 0;
+            }
+            HeroActionKind::Fire => {
+                goblin.is_fired = true;
+            }
         }
+        self.heroes.insert(hero, 0);
+        Some(HeroAction { hero, player, kind })
     }
     pub fn update_goblin_order(&mut self, goblins: &mut GoblinMap) {
-        self.goblin_order = HashMap::new();
         let players = &[Player::P1, Player::P2, Player::P3, Player::P4];
-        let mut i = 0;
-        for player in players {
-            if goblins.contains_key(player) {
-                self.goblin_order.insert(i, *player);
-                i += 1;
-            }
+        let mut order = players
+            .iter()
+            .copied()
+            .filter(|player| goblins.contains_key(player))
+            .collect::<Vec<_>>();
+        if self.turn_order_mode == TurnOrderMode::Shuffle {
+            shuffle(&mut order);
         }
-        let greed = 4;
+        self.set_goblin_order(order);
         for (k, v) in self.goblin_order.iter() {
             let goblin = goblins.get_mut(v).unwrap();
-            goblin.greed = greed - *k;
+            goblin.greed = self.turn_order_mode.starting_greed(*k);
+        }
+    }
+    /// The profile a seat's career goes to. P1 is the wallet that created the
+    /// adventure, and the other seats at the same screen get local profiles of
+    /// their own, kept under that wallet.
+    pub fn seat_owner(wallet: &Pubkey, player: Player) -> Pubkey {
+        if player == Player::P1 {
+            return *wallet;
         }
+        let mut bytes = [0; 32];
+        for (i, chunk) in bytes.chunks_mut(8).enumerate() {
+            let seed = [wallet.as_ref(), &[player.index() as u8, i as u8]].concat();
+            chunk.copy_from_slice(&fnv1a(FNV_OFFSET_BASIS, &seed).to_le_bytes());
+        }
+        Pubkey::new_from_array(bytes)
+    }
+    /// Gives every seated goblin an owner, so the whole party builds up
+    /// profiles and not just P1.
+    pub fn update_goblin_owners(&mut self, goblins: &GoblinMap) {
+        let Some(wallet) = self
+            .goblin_owners
+            .iter()
+            .find_map(|(owner, player)| (*player == Player::P1).then_some(*owner))
+        else {
+            return;
+        };
+        self.goblin_owners.retain(|_, player| goblins.contains_key(player));
+        for player in goblins.keys() {
+            if !self.goblin_owners.values().any(|p| p == player) {
+                self.goblin_owners.insert(Self::seat_owner(&wallet, *player), *player);
+            }
+        }
+    }
+    /// Reorders the goblins for a new round, applying the turn order's
+    /// handicap.
+    pub fn start_round(&mut self, goblins: &mut GoblinMap) {
+        let mut order = self.ordered_players();
+        match self.turn_order_mode {
+            TurnOrderMode::Fixed => {}
+            TurnOrderMode::Shuffle => {
+                shuffle(&mut order);
+            }
+            TurnOrderMode::Snake => {
+                order.reverse();
+            }
+            TurnOrderMode::PoorestFirst => {
+                order.sort_by_key(|player| goblins[player].loot_value());
+                // Whoever's ahead gets a little too comfortable
+                if let Some(richest) = order.last() {
+                    goblins.get_mut(richest).unwrap().add_greed(1);
+                }
+            }
+        }
+        self.set_goblin_order(order);
+    }
+    /// The goblins in turn order.
+    pub fn ordered_players(&self) -> Vec<Player> {
+        let mut order = self.goblin_order.iter().collect::<Vec<_>>();
+        order.sort();
+        order.into_iter().map(|(_, player)| *player).collect()
+    }
+    /// The goblin still on the job whose turn comes after the player's,
+    /// wrapping around to the start of the order.
+    pub fn next_player(&self, player: Player, goblins: &GoblinMap) -> Option<Player> {
+        let order = self.ordered_players();
+        let i = order.iter().position(|p| *p == player)?;
+        (1..order.len())
+            .map(|n| order[(i + n) % order.len()])
+            .find(|p| !goblins[p].is_fired)
+    }
+    fn set_goblin_order(&mut self, order: Vec<Player>) {
+        self.goblin_order = order.into_iter().enumerate().map(|(i, p)| (i as u8, p)).collect();
+    }
+}
+
+/// Fisher-Yates shuffles the turn order.
+fn shuffle(order: &mut [Player]) {
+    for i in (1..order.len()).rev() {
+        order.swap(i, random_u32() as usize % (i + 1));
     }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Turn {
     pub nonce: u8,
+    pub round: u8,
     pub num_events: u8,
+    // Luck spent on rerolls this turn
+    pub rerolls: u8,
     pub player: Player,
+    // Loot found since the last break, only kept once it's banked
+    pub pot: Vec<Loot>,
 }
 impl Turn {
     pub fn new(player: Player) -> Self {
         Self {
             nonce: 0,
+            round: 0,
             num_events: 0,
+            rerolls: 0,
             player,
+            pot: vec![],
         }
     }
+    pub const MAX_REROLLS: u8 = 2;
+    pub fn can_reroll(&self, goblin: &Goblin, settings: &Settings) -> bool {
+        !settings.has_variant(VariantRule::NoRerolls)
+            && goblin.luck > 0 && self.rerolls < Self::MAX_REROLLS
+    }
+    /// Danger grows with the location's tier and every "Keep Going" this turn.
+    pub fn danger(&self, location: &EventLocationData) -> u32 {
+        location.tier as u32 + self.num_events as u32
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
@@ -423,24 +947,266 @@ pub struct EventPhaseOutcome {
     pub choice: usize,
     pub effect: usize,
     pub accepted: bool,
+    // Whether a bribed warrior fended off an attack
+    pub is_protected: bool,
+    // How the slap fight went, once the outcome is handled
+    pub slap_fight: Option<SlapFight>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SlapFight {
+    pub opponent: Player,
+    pub won: bool,
+}
+
+/// The dungeon the party explores over the course of an adventure. It's a
+/// layered graph where every node is a location at some depth and edges lead
+/// to nodes one layer deeper. Layers are generated as the party reaches them.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Dungeon {
+    pub nodes: Vec<DungeonNode>,
+    // Nodes the party has visited in order, the last one is where they are now
+    pub route: Vec<usize>,
+}
+impl Dungeon {
+    pub const MIN_LAYER_WIDTH: usize = 2;
+    pub const MAX_LAYER_WIDTH: usize = 3;
+    pub fn new(num_locations: usize) -> Self {
+        let mut dungeon = Self {
+            nodes: vec![],
+            route: vec![],
+        };
+        dungeon.add_layer(num_locations);
+        dungeon
+    }
+    pub fn current(&self) -> Option<usize> {
+        self.route.last().copied()
+    }
+    pub fn depth(&self) -> usize {
+        self.current().map_or(0, |i| self.nodes[i].depth)
+    }
+    pub fn max_depth(&self) -> usize {
+        self.nodes.last().map_or(0, |node| node.depth)
+    }
+    pub fn layer(&self, depth: usize) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|i| self.nodes[*i].depth == depth)
+            .collect()
+    }
+    /// Nodes the party can move to next. Before entering the dungeon that's
+    /// the whole first layer.
+    pub fn paths(&self) -> Vec<usize> {
+        match self.current() {
+            Some(i) => self.nodes[i].edges.clone(),
+            None => self.layer(0),
+        }
+    }
+    /// Moves down one of `paths()` and sets up an event at the new location.
+    pub fn advance(&mut self, path: usize) -> Option<EventPhase> {
+        let next = *self.paths().get(path)?;
+        self.route.push(next);
+        let content = content();
+        if self.nodes[next].depth == self.max_depth() {
+            self.add_layer(content.locations.len());
+        }
+        let location = self.nodes[next].location;
+        let scenarios = &content.location(location).scenarios;
+        Some(EventPhase {
+            location,
+            scenario: random_u32() as usize % scenarios.len(),
+            outcome: None,
+        })
+    }
+    fn add_layer(&mut self, num_locations: usize) {
+        let parents = match self.nodes.last() {
+            Some(node) => self.layer(node.depth),
+            None => vec![],
+        };
+        let depth = self.nodes.last().map_or(0, |node| node.depth + 1);
+        let span = Self::MAX_LAYER_WIDTH - Self::MIN_LAYER_WIDTH + 1;
+        let width = Self::MIN_LAYER_WIDTH + random_u32() as usize % span;
+        let start = self.nodes.len();
+
+        // Locations don't repeat within a layer or right after a parent
+        let mut used: Vec<usize> = parents.iter().map(|p| self.nodes[*p].location).collect();
+        for _ in 0..width {
+            let mut location = random_u32() as usize % num_locations;
+            for _ in 0..num_locations {
+                if !used.contains(&location) {
+                    break;
+                }
+                location = (location + 1) % num_locations;
+            }
+            used.push(location);
+            self.nodes.push(DungeonNode {
+                location,
+                depth,
+                edges: vec![],
+            });
+        }
+
+        // Every parent gets one or two paths down and every child is reachable
+        for (i, parent) in parents.iter().enumerate() {
+            let child = start + i * width / parents.len();
+            self.nodes[*parent].edges.push(child);
+            if random_u32() % 2 == 0 {
+                let child = start + (child - start + 1) % width;
+                if !self.nodes[*parent].edges.contains(&child) {
+                    self.nodes[*parent].edges.push(child);
+                }
+            }
+        }
+        for child in start..start + width {
+            if !parents.iter().any(|p| self.nodes[*p].edges.contains(&child)) {
+                if let Some(parent) = parents.get((child - start) * parents.len() / width) {
+                    self.nodes[*parent].edges.push(child);
+                }
+            }
+        }
+        for parent in parents {
+            self.nodes[parent].edges.sort();
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DungeonNode {
+    pub location: usize,
+    pub depth: usize,
+    pub edges: Vec<usize>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct CampPhase {
     pub rummage_result: Option<RummageResult>,
     pub bribe_result: Option<BribeResult>,
+    // What the heroes did about the last goblin's behavior
+    pub hero_action: Option<HeroAction>,
+    // The goblin whose turn ended in a greedy fit
+    pub greedy_fit: Option<Player>,
 }
 impl CampPhase {
     pub fn new() -> Self {
         Self {
             rummage_result: None,
             bribe_result: None,
+            hero_action: None,
+            greedy_fit: None,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PartySack {
+    pub loot: Vec<Loot>,
+    // How many pieces the heroes think are in the sack
+    pub expected: usize,
+}
+impl PartySack {
+    pub const STARTING_LOOT_LEN: usize = 3;
+    // Share of the sack's value paid out to the goblins, in percent
+    pub const WAGE_PERCENT: u32 = 20;
+    // Taken off the wages for every piece the heroes find missing
+    pub const MISSING_PENALTY: u32 = 5;
+    // Chance to get caught rummaging per missing piece, in percent
+    pub const SUSPICION_PER_MISSING: u32 = 15;
+    pub const MAX_SUSPICION: u32 = 90;
+    pub fn new() -> Self {
+        let mut sack = Self {
+            loot: vec![],
+            expected: 0,
+        };
+        for _ in 0..Self::STARTING_LOOT_LEN {
+            sack.deposit(Loot::new(DropTable::CAMP_RUMMAGE.roll(0, 0)));
+        }
+        sack
+    }
+    /// Puts a piece of loot in the sack for the heroes to count. Everything
+    /// going into the sack goes through here so `expected` stays in sync.
+    pub fn deposit(&mut self, loot: Loot) {
+        self.loot.push(loot);
+        self.expected += 1;
+    }
+    /// Puts loot taken off a goblin back in the sack. The heroes count it as
+    /// one of the missing pieces if any are missing, or as a new piece if not.
+    pub fn recover(&mut self, loot: Loot) {
+        let is_missing = self.missing() > 0;
+        self.deposit(loot);
+        if is_missing {
+            self.expected -= 1;
+        }
+    }
+    /// A random piece of loot from the sack, left where it is.
+    pub fn peek(&self) -> Option<Loot> {
+        if self.loot.is_empty() {
+            return None;
+        }
+        let i = random_u32() as usize % self.loot.len();
+        Some(self.loot[i].clone())
+    }
+    /// Quietly takes a piece out of the sack, without the heroes knowing.
+    pub fn take(&mut self, loot: &Loot) -> Option<Loot> {
+        let i = self.loot.iter().position(|l| l == loot)?;
+        Some(self.loot.remove(i))
+    }
+    pub fn missing(&self) -> usize {
+        self.expected.saturating_sub(self.loot.len())
+    }
+    pub fn suspicion(&self) -> u32 {
+        let suspicion = self.missing() as u32 * Self::SUSPICION_PER_MISSING;
+        suspicion.min(Self::MAX_SUSPICION)
+    }
+    pub fn value(&self) -> u32 {
+        self.loot.iter().map(Loot::value).sum()
+    }
+    /// What each goblin is paid when the adventure ends, docked for every
+    /// piece of loot the heroes find missing.
+    pub fn wage(&self, num_goblins: usize) -> u32 {
+        let wages = self.value() * Self::WAGE_PERCENT / 100;
+        let wages = wages.saturating_sub(self.missing() as u32 * Self::MISSING_PENALTY);
+        wages / num_goblins.max(1) as u32
+    }
+    /// A goblin's final score, their loot plus their wage. Fired goblins
+    /// don't get paid.
+    pub fn score(&self, goblin: &Goblin, num_goblins: usize) -> u32 {
+        if goblin.is_fired {
+            return goblin.loot_value();
+        }
+        goblin.loot_value() + self.wage(num_goblins)
+    }
+    /// The best paid goblin that kept their job.
+    pub fn winner(&self, goblins: &GoblinMap) -> Option<Player> {
+        let mut players = goblins.keys().copied().collect::<Vec<_>>();
+        players.sort();
+        players
+            .into_iter()
+            .filter(|player| !goblins[player].is_fired)
+            .max_by_key(|player| self.score(&goblins[player], goblins.len()))
+    }
+}
+
+/// Something worth remembering that happened to a goblin.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AdventureEvent {
+    LootFound { player: Player, rarity: Rarity },
+    // Survived the `streak`th event in a row without a break
+    EventSurvived { player: Player, streak: u8 },
+    Completed { player: Player, won: bool },
+}
+impl AdventureEvent {
+    pub fn player(&self) -> Player {
+        match self {
+            Self::LootFound { player, .. } => *player,
+            Self::EventSurvived { player, .. } => *player,
+            Self::Completed { player, .. } => *player,
         }
     }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub enum RummageResult {
+    // The sack had nothing in it to rummage through
+    Empty,
     Fail,
     Success { loot: Loot, did_take: Option<bool> },
 }
@@ -448,36 +1214,195 @@ pub enum RummageResult {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct BribeResult {
     pub hero: HeroKind,
-    pub got: ItemKind,
+    // Refused bribes get nothing
+    pub reward: Option<BribeReward>,
     pub confirmed: bool,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BribeReward {
+    Item(ItemKind),
+    // Picked at random when the bribe is accepted
+    AnyItem,
+    // Protection from the next `GetAttacked`
+    Protection,
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum HeroActionKind {
+    Search,
+    Attack,
+    Fire,
+}
+impl HeroActionKind {
+    pub const SEARCH_SUSPICION: usize = 4;
+    pub const ATTACK_SUSPICION: usize = 7;
+    pub const FIRE_SUSPICION: usize = HeroKind::MAX_SUSPICION;
+    pub fn for_suspicion(suspicion: usize) -> Option<Self> {
+        match suspicion {
+            s if s >= Self::FIRE_SUSPICION => Some(Self::Fire),
+            s if s >= Self::ATTACK_SUSPICION => Some(Self::Attack),
+            s if s >= Self::SEARCH_SUSPICION => Some(Self::Search),
+            _ => None,
+        }
+    }
+    pub fn desc(&self) -> &'static str {
+        match self {
+            Self::Search => "searched your bag and took back the shiniest piece.",
+            Self::Attack => "gave you a good thump for your sticky fingers.",
+            Self::Fire => "has had enough of you. You're fired!",
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HeroAction {
+    pub hero: HeroKind,
+    pub player: Player,
+    pub kind: HeroActionKind,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Goblin {
+    pub name: String,
     pub health: u8,
     pub luck: u8,
     pub greed: u8,
     pub items: Vec<ItemKind>,
     pub loot: Vec<Loot>,
+    // How much loot fits in the bag, grows with bag upgrades
+    pub bag_size: u8,
+    // Loot that didn't fit, waiting to be swapped in or left behind
+    pub overflow: Vec<Loot>,
+    // Fired goblins sit out the rest of the adventure
+    pub is_fired: bool,
+    pub is_protected: bool,
+    // Picked for each seat before the adventure starts
+    pub trait_kind: Option<TraitKind>,
+    // Tallied for the owner's profile
+    pub times_caught: u8,
+    pub slap_fights_won: u8,
 }
 impl Goblin {
     pub const MAX_ITEMS_LEN: usize = 1;
     pub const MAX_LOOT_LEN: usize = 32;
+    pub const MAX_OVERFLOW_LEN: usize = 8;
+    pub const BASE_LOOT_LEN: u8 = 16;
+    pub const BAG_UPGRADE_LEN: u8 = 8;
+    // Greed past `GREEDY` makes goblins bolder and sloppier, at `MAX_GREED` they throw a fit
+    pub const GREEDY: u8 = 5;
+    pub const MAX_GREED: u8 = 10;
+    // Extra chance to get caught rummaging per point of greed bonus, in percent
+    pub const GREED_DETECTION: u32 = 10;
+    pub const MAX_NAME_LEN: usize = 8;
     pub const SIZE: usize = //
         1 + 8 + // owner
+        4 + Self::MAX_NAME_LEN + // name
         1 + // health
         1 + // luck
         1 + // greed
         Self::MAX_ITEMS_LEN * ItemKind::SIZE + // items
-        Self::MAX_LOOT_LEN * Rarity::SIZE; // loot
+        Self::MAX_LOOT_LEN * Loot::SIZE + // loot
+        1 + // bag_size
+        Self::MAX_OVERFLOW_LEN * Loot::SIZE + // overflow
+        1 + // is_fired
+        1 + // is_protected
+        1 + TraitKind::SIZE + // trait_kind
+        1 + // times_caught
+        1; // slap_fights_won
     pub fn new() -> Self {
         Self {
+            name: Self::random_name(),
             health: 2,
             luck: 0,
             greed: 0,
             items: vec![],
             loot: vec![],
+            bag_size: Self::BASE_LOOT_LEN,
+            overflow: vec![],
+            is_fired: false,
+            is_protected: false,
+            trait_kind: None,
+            times_caught: 0,
+            slap_fights_won: 0,
+        }
+    }
+    pub fn with_trait(trait_kind: Option<TraitKind>) -> Self {
+        let mut goblin = Self::new();
+        if let Some(data) = trait_kind.map(|kind| kind.data()) {
+            goblin.health += data.health;
+            goblin.luck += data.luck;
+        }
+        goblin.trait_kind = trait_kind;
+        goblin
+    }
+    /// Sticks a couple of goblin syllables together.
+    pub fn random_name() -> String {
+        let prefix = GOBLIN_NAME_PREFIXES[random_u32() as usize % GOBLIN_NAME_PREFIXES.len()];
+        let suffix = GOBLIN_NAME_SUFFIXES[random_u32() as usize % GOBLIN_NAME_SUFFIXES.len()];
+        let mut name = format!("{}{}", prefix, suffix);
+        name.truncate(Self::MAX_NAME_LEN);
+        name
+    }
+    /// Steps one syllable of a name (0 for the first, 1 for the second) to the
+    /// next one in the list, so players can pick a name instead of rolling for
+    /// one. Names that aren't made of known syllables start over from the first.
+    pub fn cycle_name(name: &str, syllable: usize) -> String {
+        let (prefix, suffix) = GOBLIN_NAME_PREFIXES
+            .iter()
+            .enumerate()
+            .find_map(|(i, prefix)| {
+                let rest = name.strip_prefix(prefix)?;
+                let j = GOBLIN_NAME_SUFFIXES.iter().position(|suffix| *suffix == rest)?;
+                Some((i, j))
+            })
+            .map_or((0, 0), |(i, j)| match syllable {
+                0 => ((i + 1) % GOBLIN_NAME_PREFIXES.len(), j),
+                _ => (i, (j + 1) % GOBLIN_NAME_SUFFIXES.len()),
+            });
+        let mut name = format!("{}{}", GOBLIN_NAME_PREFIXES[prefix], GOBLIN_NAME_SUFFIXES[suffix]);
+        name.truncate(Self::MAX_NAME_LEN);
+        name
+    }
+    pub fn trait_data(&self) -> Option<&'static TraitData> {
+        self.trait_kind.map(|kind| kind.data())
+    }
+    pub fn add_greed(&mut self, amount: u8) {
+        self.greed = self.greed.saturating_add(amount).min(Self::MAX_GREED);
+    }
+    pub fn is_greedy(&self) -> bool {
+        self.greed >= Self::GREEDY
+    }
+    /// How far past greedy the goblin is, starting at 1 once greedy.
+    pub fn greed_bonus(&self) -> u8 {
+        self.greed.saturating_sub(Self::GREEDY - 1)
+    }
+    pub fn has_room(&self) -> bool {
+        self.loot.len() < self.bag_size as usize
+    }
+    /// Whether the goblin can take another piece of loot, in the bag or set
+    /// aside to swap in.
+    pub fn can_carry(&self) -> bool {
+        self.has_room() || self.overflow.len() < Self::MAX_OVERFLOW_LEN
+    }
+    /// Puts loot in the bag, or sets it aside to swap in when the bag is full.
+    /// Hands the loot back when the goblin can't carry any more.
+    pub fn add_loot(&mut self, loot: Loot) -> Result<(), Loot> {
+        if self.has_room() {
+            self.loot.push(loot);
+            return Ok(());
         }
+        // Goblins can only juggle so much
+        if self.overflow.len() < Self::MAX_OVERFLOW_LEN {
+            self.overflow.push(loot);
+            return Ok(());
+        }
+        return Err(loot);
+    }
+    pub fn loot_value(&self) -> u32 {
+        self.loot.iter().map(Loot::value).sum()
     }
 }
 
@@ -514,24 +1439,142 @@ pub enum HeroKind {
 }
 impl HeroKind {
     pub const SIZE: usize = 1;
+    pub const MAX_SUSPICION: usize = 10;
+    // How suspicion changes with what the goblins get up to
+    pub const SUSPICION_DECAY: usize = 1;
+    pub const GREED_PER_SUSPICION: usize = 2;
+    pub const THEFT_SUSPICION: usize = 2;
+    pub const RUMMAGE_FAIL_SUSPICION: usize = 3;
+    pub const BRIBE_SUSPICION: usize = 4;
+    pub const GREEDY_FIT_SUSPICION: usize = 3;
+    pub const REFUSED_BRIBE_SUSPICION: usize = 1;
+    pub const ALL: &'static [Self] = &[
+        Self::Thief,
+        Self::Wizard,
+        Self::Warrior,
+        Self::Merchant,
+        Self::Ninja,
+    ];
+    pub fn data(&self) -> &'static HeroData {
+        HERO_DATA.iter().find(|data| data.hero == *self).unwrap()
+    }
 }
 
 #[derive(
-    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    Deserialize,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
 )]
+#[serde(rename_all = "snake_case")]
 pub enum ItemKind {
-    Foo,
-    Bar,
-    Baz,
-    Qux,
+    Lockpick,
+    Torch,
+    LuckCharm,
+    SmokeBomb,
+    BiggerBag,
 }
 impl ItemKind {
     pub const SIZE: usize = 1;
+    pub const ALL: &'static [Self] = &[
+        Self::Lockpick,
+        Self::Torch,
+        Self::LuckCharm,
+        Self::SmokeBomb,
+        Self::BiggerBag,
+    ];
+    /// Name used to refer to the item in content files.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lockpick => "lockpick",
+            Self::Torch => "torch",
+            Self::LuckCharm => "luck_charm",
+            Self::SmokeBomb => "smoke_bomb",
+            Self::BiggerBag => "bigger_bag",
+        }
+    }
+    pub fn desc(&self) -> &'static str {
+        match self {
+            Self::Lockpick => "Opens locks that would otherwise need a key.",
+            Self::Torch => "Lights the way through the darkest places.",
+            Self::LuckCharm => "Rub it to feel a little luckier.",
+            Self::SmokeBomb => "Makes for a quick and smelly getaway.",
+            Self::BiggerBag => "Stitch it on for more room in your loot bag.",
+        }
+    }
+    pub fn is_usable(&self) -> bool {
+        match self {
+            Self::LuckCharm => true,
+            Self::BiggerBag => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum TraitKind {
+    StickyFingers,
+    ThickSkull,
+    LuckyCharm,
+    Blabbermouth,
+}
+impl TraitKind {
+    pub const SIZE: usize = 1;
+    pub const ALL: &'static [Self] = &[
+        Self::StickyFingers,
+        Self::ThickSkull,
+        Self::LuckyCharm,
+        Self::Blabbermouth,
+    ];
+    pub fn data(&self) -> &'static TraitData {
+        TRAIT_DATA.iter().find(|data| data.kind == *self).unwrap()
+    }
+    /// The next pick when cycling through traits, with no trait at either end.
+    pub fn cycle(kind: Option<Self>) -> Option<Self> {
+        match kind {
+            None => Some(Self::ALL[0]),
+            Some(kind) => {
+                let i = Self::ALL.iter().position(|k| *k == kind).unwrap();
+                Self::ALL.get(i + 1).copied()
+            }
+        }
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Loot {
     pub rarity: Rarity,
+    // Index among the catalog entries of the same rarity
+    pub kind: u8,
+}
+impl Loot {
+    pub const SIZE: usize = Rarity::SIZE + 1;
+    /// Picks a random catalogued piece of loot of the given rarity.
+    pub fn new(rarity: Rarity) -> Self {
+        let kinds = LOOT_CATALOG.iter().filter(|data| data.rarity == rarity).count();
+        let kind = (random_u32() as usize % kinds.max(1)) as u8;
+        Self { rarity, kind }
+    }
+    pub fn data(&self) -> &'static LootData {
+        LOOT_CATALOG
+            .iter()
+            .filter(|data| data.rarity == self.rarity)
+            .nth(self.kind as usize)
+            .unwrap_or_else(|| LOOT_CATALOG.iter().find(|data| data.rarity == self.rarity).unwrap())
+    }
+    /// How much the loot is worth, used both for display and scoring.
+    pub fn value(&self) -> u32 {
+        self.data().value
+    }
 }
 
 #[derive(
@@ -546,10 +1589,79 @@ pub enum Rarity {
 }
 impl Rarity {
     pub const SIZE: usize = 1;
+    pub const ALL: &'static [Self] = &[
+        Self::Common,
+        Self::Uncommon,
+        Self::Rare,
+        Self::Legendary,
+        Self::Epic,
+    ];
+}
+
+/// Rolls one of an action's outcomes for a goblin.
+fn roll_outcome(
+    choice: usize,
+    action: &EventScenarioAction,
+    danger: u32,
+    goblin: &mut Goblin,
+) -> EventPhaseOutcome {
+    // Danger makes the worst outcomes more likely
+    let steal_weight = goblin.trait_data().map_or(0, |data| data.steal_weight);
+    let weights = action
+        .outcomes
+        .iter()
+        .map(|o| {
+            let weight = o.effect.scaled_weight(o.weight, danger);
+            // Sticky fingers find more to steal
+            match o.effect {
+                EventResult::StealLoot | EventResult::StealItem => weight + weight * steal_weight / 100,
+                _ => weight,
+            }
+        })
+        .collect::<Vec<_>>();
+    let effect = roll_weighted(&weights);
+    // A bribed warrior steps in, once
+    let is_protected = action.outcomes[effect].effect == EventResult::GetAttacked && goblin.is_protected;
+    if is_protected {
+        goblin.is_protected = false;
+    }
+    EventPhaseOutcome {
+        choice,
+        effect,
+        accepted: false,
+        is_protected,
+        slap_fight: None,
+    }
+}
+
+/// Picks an index with probability proportional to its weight.
+pub fn roll_weighted(weights: &[u32]) -> usize {
+    let total = weights.iter().sum::<u32>();
+    if total == 0 {
+        return 0;
+    }
+    let mut roll = random_u32() % total;
+    for (i, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            return i;
+        }
+        roll -= weight;
+    }
+    return weights.len() - 1;
 }
 
 #[derive(
-    BorshSerialize, BorshDeserialize, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    Deserialize,
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
 )]
 pub enum EventResult {
     GetLoot,       // "Found some valuable loot"
@@ -604,6 +1716,17 @@ impl EventResult {
             Self::OK => "Nothing eventful occurs.",
         }
     }
+    /// How much an outcome's weight grows per point of danger, in percent.
+    pub fn danger_bonus(&self) -> u32 {
+        match self {
+            Self::GetAttacked => 25,
+            Self::LoseLoot => 25,
+            _ => 0,
+        }
+    }
+    pub fn scaled_weight(&self, weight: u32, danger: u32) -> u32 {
+        weight + weight * danger * self.danger_bonus() / 100
+    }
     pub fn is_good(&self) -> bool {
         match self {
             Self::GetLoot => true,
@@ -696,3 +1819,129 @@ impl EventResult {
 //         return i - 1;
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A started adventure whose only goblin can't carry any more loot. The
+    /// first piece that didn't fit is the only epic one.
+    fn full_bag_adventure() -> Adventure {
+        let mut adventure = Adventure::new(Pubkey::default());
+        adventure.start_adventure().unwrap();
+        if let AdventureState::Started(goblins, ..) = &mut adventure.state {
+            let goblin = goblins.get_mut(&Player::P1).unwrap();
+            while goblin.add_loot(Loot::new(Rarity::Common)).is_ok() {}
+            goblin.overflow[0] = Loot::new(Rarity::Epic);
+        }
+        adventure
+    }
+
+    /// Every piece of loot in play, carried, in the pot or in the sack.
+    fn all_loot(adventure: &Adventure) -> Vec<Loot> {
+        let AdventureState::Started(goblins, _settings, turn, _phase, _dungeon, sack) =
+            &adventure.state
+        else {
+            panic!("the adventure hasn't started");
+        };
+        let mut loot = sack.loot.clone();
+        loot.extend(turn.pot.iter().cloned());
+        for goblin in goblins.values() {
+            loot.extend(goblin.loot.iter().cloned());
+            loot.extend(goblin.overflow.iter().cloned());
+        }
+        loot.sort_by_key(|loot| (loot.rarity, loot.kind));
+        loot
+    }
+
+    fn sack(adventure: &Adventure) -> &PartySack {
+        match &adventure.state {
+            AdventureState::Started(_, _, _, _, _, sack) => sack,
+            _ => panic!("the adventure hasn't started"),
+        }
+    }
+
+    #[test]
+    fn swapping_overflow_keeps_loot() {
+        let mut adventure = full_bag_adventure();
+        let loot = all_loot(&adventure);
+        adventure.swap_overflow(Player::P1, 0).unwrap();
+        assert_eq!(all_loot(&adventure), loot);
+        if let AdventureState::Started(goblins, ..) = &adventure.state {
+            assert_eq!(goblins[&Player::P1].loot[0].rarity, Rarity::Epic);
+        }
+        assert_eq!(sack(&adventure).missing(), 0);
+    }
+
+    #[test]
+    fn leaving_overflow_keeps_loot() {
+        let mut adventure = full_bag_adventure();
+        let loot = all_loot(&adventure);
+        let sack_len = sack(&adventure).loot.len();
+        while adventure.leave_overflow(Player::P1).is_ok() {}
+        assert_eq!(all_loot(&adventure), loot);
+        let left = sack(&adventure).loot.len() - sack_len;
+        assert_eq!(left, Goblin::MAX_OVERFLOW_LEN);
+        assert_eq!(sack(&adventure).missing(), 0);
+        assert!(adventure.swap_overflow(Player::P1, 0).is_err());
+    }
+
+    #[test]
+    fn cycling_names() {
+        assert_eq!(Goblin::cycle_name("Snitch", 0), "Gritch");
+        assert_eq!(Goblin::cycle_name("Snitch", 1), "Snub");
+        // Both syllables wrap around
+        assert_eq!(Goblin::cycle_name("Gobitch", 0), "Snitch");
+        assert_eq!(Goblin::cycle_name("Snick", 1), "Snitch");
+        // Prefixes that start other prefixes don't get in the way
+        assert_eq!(Goblin::cycle_name("Skrub", 1), "Skrnik");
+        assert_eq!(Goblin::cycle_name("Bob", 0), "Snitch");
+    }
+
+    #[test]
+    fn every_seat_builds_a_profile() {
+        let wallet = Pubkey::new_from_array([7; 32]);
+        let mut adventure = Adventure::new(wallet);
+        if let AdventureState::Preparing(goblins, _) = &mut adventure.state {
+            goblins.insert(Player::P3, Goblin::new());
+        }
+        adventure.start_adventure().unwrap();
+        let AdventureState::Started(goblins, settings, _, _, _, sack) = adventure.state.clone() else {
+            panic!("the adventure didn't start");
+        };
+        adventure.state = AdventureState::Complete(goblins, settings, sack);
+        let mut profiles = crate::ProfileStore::new();
+        profiles.record(&adventure).unwrap();
+        let p3 = Settings::seat_owner(&wallet, Player::P3);
+        assert_ne!(p3, wallet);
+        for owner in [wallet, p3] {
+            assert_eq!(profiles.get(&owner).map(|profile| profile.adventures_played), Some(1));
+        }
+        assert_eq!(profiles.profiles.len(), 2);
+    }
+
+    #[test]
+    fn shuffle_reshuffles_every_round() {
+        let mut goblins = [Player::P1, Player::P2, Player::P3, Player::P4]
+            .into_iter()
+            .map(|player| (player, Goblin::new()))
+            .collect::<GoblinMap>();
+        let mut settings = Settings::new();
+        settings.turn_order_mode = TurnOrderMode::Shuffle;
+        seed_rng(Some(FNV_OFFSET_BASIS));
+        settings.update_goblin_order(&mut goblins);
+        let first = settings.ordered_players();
+        let rounds = (0..8)
+            .map(|_| {
+                settings.start_round(&mut goblins);
+                settings.ordered_players()
+            })
+            .collect::<Vec<_>>();
+        seed_rng(None);
+        assert!(rounds.iter().any(|order| *order != first), "{:?}", rounds);
+        for mut order in rounds {
+            order.sort();
+            assert_eq!(order, [Player::P1, Player::P2, Player::P3, Player::P4]);
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
+use turbo::solana::solana_sdk;
 
 use super::*;
 
@@ -11,6 +13,7 @@ pub const WHITE: u32 = 0xffffffff;
 pub const MAGENTA: u32 = 0xff00ffff;
 pub const RED: u32 = 0xff0000ff;
 pub const GREEN: u32 = 0x00ff00ff;
+pub const YELLOW: u32 = 0xffff00ff;
 pub const BACKDROP: u32 = 0x000000ee;
 pub const TRANSPARENT: u32 = 0x00000000;
 pub const FG: u32 = 0x472e1fff;
@@ -24,21 +27,61 @@ impl GUI {
     pub fn dispatch(&mut self, cmd: Command) {
         self.commands.push_front(cmd);
     }
+    pub fn push_toast(&mut self, msg: &str) {
+        self.toasts.push_back(Toast {
+            msg: msg.to_string(),
+            timer: Toast::DURATION,
+        });
+    }
     pub fn is_overlay_open(&self) -> bool {
-        self.goblin_dialog != None || self.loot_inspector != None
+        self.goblin_dialog != None || self.loot_inspector != None || self.dungeon_map != None
     }
-    pub fn open_goblin_dialog(&mut self, player: Player, message: &str, on_close: Option<Command>) {
-        let message = insert_line_breaks(message, 36);
+    pub fn open_goblin_dialog(
+        &mut self,
+        player: Player,
+        script: &str,
+        ctx: &DialogContext,
+        on_close: Option<Command>,
+    ) {
+        let page = match DialogScript::parse(script) {
+            Ok(parsed) => parsed.page(0, ctx),
+            Err(err) => {
+                turbo::println!("Invalid dialog script {}", err);
+                DialogPage::text(UNREACHABLE_DIALOG)
+            }
+        };
         self.goblin_dialog = Some(GoblinDialog {
             player,
-            message,
+            name: ctx.goblin.to_string(),
+            script: script.to_string(),
+            message: insert_line_breaks(&page.message, 36),
+            choices: page.choices,
+            next: page.next,
             max_len: 0,
             on_close,
         });
     }
+    pub fn goto_goblin_dialog_node(
+        &mut self,
+        node: Option<usize>,
+        ctx: &DialogContext,
+    ) -> Option<Command> {
+        let (Some(node), Some(goblin_dialog)) = (node, &mut self.goblin_dialog) else {
+            return self.close_goblin_dialog();
+        };
+        if let Ok(parsed) = DialogScript::parse(&goblin_dialog.script) {
+            let page = parsed.page(node, ctx);
+            goblin_dialog.message = insert_line_breaks(&page.message, 36);
+            goblin_dialog.choices = page.choices;
+            goblin_dialog.next = page.next;
+            goblin_dialog.max_len = 0;
+            return None;
+        }
+        return self.close_goblin_dialog();
+    }
     pub fn fast_forward_goblin_dialog(&mut self) {
         if let Some(goblin_dialog) = &mut self.goblin_dialog {
-            goblin_dialog.max_len = goblin_dialog.message.len();
+            goblin_dialog.max_len = goblin_dialog.message.chars().count();
         }
     }
     pub fn close_goblin_dialog(&mut self) -> Option<Command> {
@@ -51,11 +94,27 @@ impl GUI {
         self.loot_inspector = Some(GoblinLootInspector {
             player,
             selected: None,
+            page: 0,
+            bribe_target: None,
         });
     }
+    pub fn set_loot_inspector_bribe_target(&mut self, hero: HeroKind) {
+        if let Some(ref mut inspector) = self.loot_inspector {
+            inspector.bribe_target = Some(hero);
+        }
+    }
     pub fn close_goblin_loot_inspector(&mut self) {
         self.loot_inspector = None;
     }
+    pub fn take_loot_inspector_selection(&mut self) -> Option<LootSlot> {
+        self.loot_inspector.as_mut().and_then(|inspector| inspector.selected.take())
+    }
+    pub fn open_dungeon_map(&mut self) {
+        self.dungeon_map = Some(DungeonMap {});
+    }
+    pub fn close_dungeon_map(&mut self) {
+        self.dungeon_map = None;
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -65,18 +124,29 @@ impl GUI {
 impl PhaseActionsSection {
     pub const MAX_LINE_LEN: usize = 23;
     pub const MAX_FONT_L_LINE_LEN: usize = 14;
+    pub const RISK_METER_LEN: u32 = 10;
     pub const DESC_CAMP_DEFAULT: &'static str = "The flickering campfire casts a warm glow, offering a brief respite from the adventurers' relentless journey...";
     pub const DESC_CAMP_RUMMAGE_FAIL: &'static str = "Your attempt to rummage the party's loot was noticed by the others. You play it off with a clumsy chuckle and whistle a tune as they make a hasty retreat.";
+    pub const DESC_CAMP_RUMMAGE_EMPTY: &'static str = "You plunge your arm into the loot sack up to the shoulder and come up with nothing but lint. There's not a single shiny left in there to pocket.";
     pub const DESC_CAMP_RUMMAGE_SUCCESS: &'static str = "With deft fingers and a sly grin, you rummage through the loot sack, uncovering hidden treasures. Your eyes sparkle with glee as you decide whether to pocket your newfound riches, unnoticed by all.";
-    pub fn draw_event_actions(&mut self, event_phase: &EventPhase) -> Option<EventPhaseAction> {
+    pub fn draw_event_actions(
+        &mut self,
+        event_phase: &EventPhase,
+        goblins: &GoblinMap,
+        turn: &Turn,
+        dungeon: &Dungeon,
+        settings: &Settings,
+    ) -> Option<EventPhaseAction> {
         let mut event = None;
+        let goblin = &goblins[&turn.player];
 
         set_camera(0, 0);
         let mut x = 128;
         let mut y = 0;
 
         // Event location data
-        let data = &ALL_EVENT_LOCATION_DATA[event_phase.location % ALL_EVENT_LOCATION_DATA.len()];
+        let content = content();
+        let data = content.location(event_phase.location);
 
         // Background
         let [_w, h] = resolution();
@@ -85,13 +155,45 @@ impl PhaseActionsSection {
         y += 8;
 
         // Title
-        let msg = &insert_line_breaks(data.name, Self::MAX_FONT_L_LINE_LEN);
+        let msg = &insert_line_breaks(&data.name, Self::MAX_FONT_L_LINE_LEN);
         text!(msg, font = Font::L, x = x, y = y);
         y += 8 * msg.lines().count() as i32;
+        y += 4;
+
+        // Danger
+        let danger = turn.danger(data);
+        let msg = &format!("TIER {} - DANGER {}", data.tier, danger);
+        #[rustfmt::skip]
+        text!(msg, font = Font::S, x = x, y = y, color = if danger > EventLocationData::MAX_TIER as u32 { RED } else { WHITE });
+        y += 6;
+        y += 4;
+
+        // Pot - loot at risk until the goblin takes a break
+        let msg = &format!("POT: {} LOOT", turn.pot.len());
+        #[rustfmt::skip]
+        text!(msg, font = Font::S, x = x, y = y, color = if turn.pot.is_empty() { WHITE } else { YELLOW });
+        y += 6;
+        y += 2;
+
+        // Risk meter - fills up and turns red as the streak goes on
+        let filled = danger.min(PhaseActionsSection::RISK_METER_LEN) as i32;
+        for i in 0..PhaseActionsSection::RISK_METER_LEN as i32 {
+            let color = if i >= filled {
+                0x333333ff
+            } else if i < 3 {
+                GREEN
+            } else if i < 6 {
+                YELLOW
+            } else {
+                RED
+            };
+            rect!(w = 10, h = 4, x = x + i * 12, y = y, fill = color);
+        }
+        y += 4;
         y += 8;
 
         // Description
-        let msg = data.description;
+        let msg = &data.description;
         let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
         text!(&msg, x = x, y = y, color = WHITE);
         y += 8 * msg.lines().count() as i32;
@@ -99,7 +201,7 @@ impl PhaseActionsSection {
 
         // Scenario
         let scenario = &data.scenarios[event_phase.scenario];
-        let msg = scenario.description;
+        let msg = &scenario.description;
         let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
         text!(&msg, x = x, y = y, color = WHITE);
         y += 8 * msg.lines().count() as i32;
@@ -110,11 +212,13 @@ impl PhaseActionsSection {
             choice,
             effect,
             accepted,
+            is_protected,
+            slap_fight,
         }) = &event_phase.outcome
         {
             let action = &data.scenarios[event_phase.scenario].actions[*choice];
             let outcome = &action.outcomes[*effect];
-            let is_good_outcome = outcome.effect.is_good();
+            let is_good_outcome = outcome.effect.is_good() || *is_protected;
 
             let msg = &outcome.effect.desc().to_ascii_uppercase();
             let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
@@ -123,12 +227,44 @@ impl PhaseActionsSection {
             y += 8 * msg.lines().count() as i32;
             y += 8;
 
-            let msg = outcome.description;
+            if !*accepted && !is_good_outcome && !turn.pot.is_empty() {
+                let msg = "POT LOST!";
+                text!(&msg, x = x, y = y, color = RED);
+                y += 8;
+                y += 8;
+            }
+            if !*accepted && *is_protected {
+                let msg = "THE WARRIOR FENDS THEM OFF!";
+                let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
+                text!(&msg, x = x, y = y, color = GREEN);
+                y += 8 * msg.lines().count() as i32;
+                y += 8;
+            }
+
+            let msg = &outcome.description;
             let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
             text!(&msg, x = x, y = y, color = WHITE);
             y += 8 * msg.lines().count() as i32;
             y += 8;
 
+            if let Some(SlapFight { opponent, won }) = slap_fight {
+                let name = goblins[opponent].name.to_ascii_uppercase();
+                let msg = if *won {
+                    format!("YOU OUT-SLAPPED {}!", name)
+                } else {
+                    format!("{} OUT-SLAPPED YOU!", name)
+                };
+                text!(&msg, x = x, y = y, color = if *won { GREEN } else { RED });
+                y += 8;
+                y += 8;
+            }
+            if *accepted && goblin.greed >= Goblin::MAX_GREED {
+                let msg = "TOO GREEDY! KEEPING GOING WILL END IN A FIT.";
+                let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
+                text!(&msg, x = x, y = y, color = RED);
+                y += 8 * msg.lines().count() as i32;
+                y += 8;
+            }
             if *accepted {
                 let msg = "WHAT WILL YOU DO NEXT?";
                 text!(&msg, x = x, y = y, color = WHITE);
@@ -136,18 +272,27 @@ impl PhaseActionsSection {
                 y += 8;
             }
 
+            // Keeping going means picking one of the paths leading deeper
             #[rustfmt::skip]
             let mut actions = vec![];
             if *accepted {
                 if is_good_outcome {
-                    actions.push((EventPhaseAction::KeepGoingStart, "> Keep Going"));
+                    for (i, node) in dungeon.paths().iter().enumerate() {
+                        let name = &content.location(dungeon.nodes[*node].location).name;
+                        actions.push((EventPhaseAction::KeepGoingStart(i), format!("> {}", name)));
+                    }
                 }
-                actions.push((EventPhaseAction::TakeABreakStart, "> Take a Break"));
+                actions.push((EventPhaseAction::TakeABreakStart, "> Take a Break".to_string()));
             } else {
-                actions.push((EventPhaseAction::ConfirmOutcome(false), "> Next..."));
+                // Luck can undo a bad outcome, a limited number of times per turn
+                if !is_good_outcome && turn.can_reroll(goblin, settings) {
+                    let msg = format!("> Reroll (LUCK {})", goblin.luck);
+                    actions.push((EventPhaseAction::Reroll, msg));
+                }
+                actions.push((EventPhaseAction::ConfirmOutcome(false), "> Next...".to_string()));
             }
             for (action, msg) in actions {
-                if cbutton(Font::S, x, y, Some(128 - 16), BLACK, WHITE, WHITE, msg) {
+                if cbutton(Font::S, x, y, Some(128 - 16), BLACK, WHITE, WHITE, &msg) {
                     event = Some(action);
                 }
                 y += 16;
@@ -157,21 +302,25 @@ impl PhaseActionsSection {
         }
 
         // Actions - No action taken
-        #[rustfmt::skip]
-        let actions = [
-            (EventPhaseAction::TakeRisk, &scenario.actions[0]),
-            (EventPhaseAction::PlayItSafe, &scenario.actions[1]),
-        ];
-        for (action, a) in actions {
-            if cbutton(Font::S, x, y, Some(128 - 16), BLACK, WHITE, WHITE, a.label) {
-                event = Some(action);
+        // Actions the goblin doesn't meet the requirements for are greyed out
+        for (i, a) in scenario.actions.iter().enumerate() {
+            let is_available = a.is_available(goblin);
+            let (color, fill) = if is_available { (BLACK, WHITE) } else { (WHITE, 0x555555ff) };
+            if cbutton(Font::S, x, y, Some(128 - 16), color, fill, WHITE, &a.label) && is_available {
+                event = Some(EventPhaseAction::Choose(i));
             }
             y += 16;
         }
 
         return event;
     }
-    pub fn draw_camp_actions(&mut self, phase: &CampPhase) -> Option<CampPhaseAction> {
+    pub fn draw_camp_actions(
+        &mut self,
+        phase: &CampPhase,
+        goblins: &GoblinMap,
+        sack: &PartySack,
+        settings: &Settings,
+    ) -> Option<CampPhaseAction> {
         let mut event = None;
 
         set_camera(0, 0);
@@ -197,6 +346,53 @@ impl PhaseActionsSection {
         y += 8 * msg.lines().count() as i32;
         y += 8;
 
+        // Party sack - the heroes notice when it shrinks
+        let msg = &format!("PARTY SACK: {} LOOT", sack.loot.len());
+        text!(msg, font = Font::S, x = x, y = y, color = WHITE);
+        y += 6;
+        y += 2;
+        if sack.missing() > 0 {
+            let msg = &format!("{} MISSING ({}% RISK)", sack.missing(), sack.suspicion());
+            text!(msg, font = Font::S, x = x, y = y, color = RED);
+            y += 6;
+            y += 2;
+        }
+        y += 6;
+
+        // Heroes - each one's suspicion of the goblins
+        for hero in settings.hero_party() {
+            let suspicion = settings.heroes[&hero];
+            let msg = &format!("{:?}", hero).to_ascii_uppercase();
+            text!(msg, font = Font::S, x = x, y = y, color = WHITE);
+            for i in 0..HeroKind::MAX_SUSPICION {
+                #[rustfmt::skip]
+                let color = if i >= suspicion { 0x333333ff } else if HeroActionKind::for_suspicion(i + 1).is_some() { RED } else { YELLOW };
+                rect!(w = 5, h = 4, x = x + 50 + i as i32 * 6, y = y, fill = color);
+            }
+            y += 6;
+            y += 2;
+        }
+        y += 6;
+
+        // The last goblin's tantrum
+        if let Some(player) = &phase.greedy_fit {
+            let name = &goblins[player].name;
+            let msg = &format!("{} threw a greedy fit and the heroes scooped up their pot!", name);
+            let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
+            text!(&msg, x = x, y = y, color = YELLOW);
+            y += 8 * msg.lines().count() as i32;
+            y += 8;
+        }
+
+        // What the heroes did about the last goblin
+        if let Some(HeroAction { hero, player, kind }) = &phase.hero_action {
+            let msg = &format!("{}: The {:?} {}", goblins[player].name, hero, kind.desc());
+            let msg = insert_line_breaks(msg, PhaseActionsSection::MAX_LINE_LEN);
+            text!(&msg, x = x, y = y, color = RED);
+            y += 8 * msg.lines().count() as i32;
+            y += 8;
+        }
+
         match self.camp {
             CampActionMenu::Default => {
                 // Description
@@ -220,6 +416,21 @@ impl PhaseActionsSection {
                 }
             }
             CampActionMenu::RummageResult => match &phase.rummage_result {
+                Some(RummageResult::Empty) => {
+                    // Description
+                    text!("THE SACK IS EMPTY", x = x, y = y, color = YELLOW);
+                    y += 8;
+                    y += 8;
+                    let msg = insert_line_breaks(Self::DESC_CAMP_RUMMAGE_EMPTY, Self::MAX_LINE_LEN);
+                    text!(&msg, x = x, y = y, color = WHITE);
+                    y += 8 * msg.lines().count() as i32;
+                    y += 8;
+                    // Actions
+                    let action = CampPhaseAction::BackToDefaultMenu;
+                    if cbutton(Font::S, x, y, Some(128 - 16), BLACK, WHITE, WHITE, "> Back") {
+                        event = Some(action);
+                    }
+                }
                 Some(RummageResult::Fail) => {
                     // Description
                     text!("YOU GOT CAUGHT", x = x, y = y, color = RED);
@@ -267,9 +478,63 @@ impl PhaseActionsSection {
                     //
                 }
             },
-            CampActionMenu::BribeResult => {
-                //
+            CampActionMenu::BribeChoice => {
+                // Description
+                text!("WHO WILL YOU BRIBE?", x = x, y = y, color = WHITE);
+                y += 8;
+                y += 8;
+                // Actions
+                let mut actions = vec![];
+                for hero in settings.hero_party() {
+                    let msg = format!("> {:?} ({})", hero, hero.data().wants.to_ascii_lowercase());
+                    actions.push((CampPhaseAction::BribeChoose(hero), msg));
+                }
+                actions.push((CampPhaseAction::BackToDefaultMenu, "> Back".to_string()));
+                for (action, msg) in actions {
+                    if cbutton(Font::S, x, y, Some(128 - 16), BLACK, WHITE, WHITE, &msg) {
+                        event = Some(action);
+                    }
+                    y += 16;
+                }
             }
+            CampActionMenu::BribeResult => match &phase.bribe_result {
+                Some(BribeResult { hero, reward, .. }) => {
+                    let data = hero.data();
+                    // Portrait
+                    draw_hero_portrait(*hero, x, y);
+                    let msg = &format!("{:?}", hero).to_ascii_uppercase();
+                    text!(msg, x = x + 28, y = y + 4, color = WHITE);
+                    #[rustfmt::skip]
+                    let (msg, color) = if reward.is_some() { ("ACCEPTS", GREEN) } else { ("REFUSES", RED) };
+                    text!(msg, x = x + 28, y = y + 14, color = color);
+                    y += 24;
+                    y += 8;
+                    // Dialog
+                    let msg = &format!("\"{}\"", if reward.is_some() { data.accept } else { data.refuse });
+                    let msg = insert_line_breaks(msg, Self::MAX_LINE_LEN);
+                    text!(&msg, x = x, y = y, color = WHITE);
+                    y += 8 * msg.lines().count() as i32;
+                    y += 8;
+                    // Reward
+                    let msg = &match reward {
+                        Some(BribeReward::Item(item)) => format!("GOT: {}", item.name().replace('_', " ")),
+                        Some(BribeReward::Protection) => "PROTECTED FROM THE NEXT AMBUSH".to_string(),
+                        _ => "YOU KEEP YOUR LOOT".to_string(),
+                    };
+                    let msg = insert_line_breaks(&msg.to_ascii_uppercase(), Self::MAX_LINE_LEN);
+                    text!(&msg, font = Font::S, x = x, y = y, color = YELLOW);
+                    y += 8 * msg.lines().count() as i32;
+                    y += 8;
+                    // Actions
+                    let msg = "> Back";
+                    if cbutton(Font::S, x, y, Some(128 - 16), BLACK, WHITE, WHITE, msg) {
+                        event = Some(CampPhaseAction::BackToDefaultMenu);
+                    }
+                }
+                _ => {
+                    //
+                }
+            },
         }
 
         return event;
@@ -308,6 +573,9 @@ impl GoblinList {
                 Player::P4 => 4,
             });
             sprite!(goblin_key, x = x, y = y);
+            if let Some(data) = goblins[&player].trait_data() {
+                text!(data.name, font = Font::S, x = x, y = y + 26, color = WHITE);
+            }
             if cdiv(32, 32, x, y, TRANSPARENT, TRANSPARENT) {
                 event = Some(GoblinListEvent::OpenGoblinDialog(*player));
             }
@@ -316,15 +584,19 @@ impl GoblinList {
             x += 36;
             y += 6;
             let goblin = &goblins[&player];
+            #[rustfmt::skip]
+            let greed_color = if goblin.greed >= Goblin::MAX_GREED { RED } else if goblin.is_greedy() { YELLOW } else { WHITE };
+            let msg = &format!("{:?} {}", player, goblin.name.to_ascii_uppercase());
+            text!(msg, font = Font::S, x = x, y = y, color = WHITE);
+            y += 6;
             let attributes = [
-                ("player", &format!("{:?}", player)),
-                ("health", &goblin.health.to_string()),
-                ("luck  ", &goblin.luck.to_string()),
-                ("greed ", &goblin.greed.to_string()),
+                ("health", &goblin.health.to_string(), WHITE),
+                ("luck  ", &goblin.luck.to_string(), WHITE),
+                ("greed ", &goblin.greed.to_string(), greed_color),
             ];
-            for (key, val) in attributes {
+            for (key, val, color) in attributes {
                 let key = key.to_ascii_uppercase();
-                text!(&format!("{key}: {:0>2}", val), font = Font::S, x = x, y = y);
+                text!(&format!("{key}: {:0>2}", val), font = Font::S, x = x, y = y, color = color);
                 y += 6;
             }
             let prev_y = y;
@@ -333,14 +605,7 @@ impl GoblinList {
             rect!(w = 1, h = 24, x = x, y = y, fill = 0xffffff33);
             x += 5;
             y += 1;
-            let total_loot_value = goblin.loot.iter().fold(0, |acc, loot| match loot.rarity {
-                Rarity::Common => acc + 1,
-                Rarity::Uncommon => acc + 2,
-                Rarity::Rare => acc + 3,
-                Rarity::Legendary => acc + 4,
-                Rarity::Epic => acc + 5,
-            });
-            let msg = &format!("${:0>3}", total_loot_value);
+            let msg = &format!("${:0>3}", goblin.loot_value());
             text!(msg, x = x, y = y, font = Font::M, color = WHITE);
             y += 10;
             if cbutton(Font::S, x - 1, y, None, BLACK, WHITE, BLACK, "BAG") {
@@ -361,6 +626,9 @@ impl GoblinList {
             } else {
                 rect!(w = 120, h = 32, x = left, y = top, fill = BACKDROP);
             }
+            if goblin.is_fired {
+                text!("FIRED", font = Font::L, x = left + 40, y = top + 12, color = RED);
+            }
         }
         return event;
     }
@@ -373,15 +641,24 @@ impl GoblinList {
 impl GoblinDialog {
     pub fn draw(&mut self) -> Option<GoblinDialogEvent> {
         let mut event = None;
-        let is_entire_message = self.max_len >= self.message.len();
+        let is_entire_message = self.max_len >= self.message.chars().count();
 
         set_camera(0, 0);
         let [w, h] = resolution();
 
+        // Choices have to be picked explicitly, everything else moves the dialog along
+        let on_continue = if !self.choices.is_empty() {
+            None
+        } else if self.next.is_some() {
+            Some(GoblinDialogEvent::Next)
+        } else {
+            Some(GoblinDialogEvent::Close)
+        };
+
         // Drop-shadow
         if cdiv(w, h - 32, 0, 0, BACKDROP, BACKDROP) && self.max_len > 0 {
             if is_entire_message {
-                let _ = event.insert(GoblinDialogEvent::Close);
+                event = on_continue.clone();
             }
         }
         let x = 0;
@@ -390,11 +667,22 @@ impl GoblinDialog {
         // Panel
         if cdiv(w, 32, x, y, BLACK, WHITE) {
             if is_entire_message {
-                let _ = event.insert(GoblinDialogEvent::Close);
+                event = on_continue.clone();
             } else {
                 let _ = event.insert(GoblinDialogEvent::FastForward);
-                // TODO: skip to next page of dialog
-                // let _ = event.insert(GoblinDialogEvent::Next);
+            }
+        }
+
+        // Choices
+        if is_entire_message {
+            // Leave room for the name tag
+            let mut choice_y = y - 12 - 16 * self.choices.len() as i32;
+            for (i, choice) in self.choices.iter().enumerate() {
+                let msg = &format!("> {}", choice.text);
+                if cbutton(Font::S, 66, choice_y, Some(w - 66 - 12), BLACK, WHITE, WHITE, msg) {
+                    let _ = event.insert(GoblinDialogEvent::Choose(i));
+                }
+                choice_y += 16;
             }
         }
 
@@ -419,10 +707,15 @@ impl GoblinDialog {
             color = bg
         );
 
+        // Name tag
+        let name = self.name.to_ascii_uppercase();
+        rect!(w = 5 * name.len() as u32 + 4, h = 9, x = 64, y = y - 8, fill = BLACK);
+        text!(&name, x = 66, y = y - 6, font = Font::S, color = WHITE);
+
         // Message
         let x = x + 66;
         let y = y + 5;
-        let msg = &self.message[0..self.max_len.min(self.message.len())];
+        let msg = reveal(&self.message, self.max_len);
         text!(msg, x = x, y = y, color = WHITE);
 
         // Indicator
@@ -443,7 +736,20 @@ impl GoblinDialog {
 ////////////////////////////////////////////////////////////////////////////////
 
 impl GoblinLootInspector {
-    pub fn draw(&mut self, goblins: &HashMap<Player, Goblin>) -> Option<GoblinLootInspectorEvent> {
+    pub const DETAILS_WIDTH: u32 = 182;
+    pub const DETAILS_HEIGHT: u32 = 66;
+    pub const MAX_LINE_LEN: usize = 34;
+    pub const SLOT_SIZE: u32 = 14;
+    pub const PAGE_LEN: usize = 26;
+    pub fn select(&mut self, slot: LootSlot) {
+        self.selected = if self.selected == Some(slot.clone()) { None } else { Some(slot) };
+    }
+    pub fn draw(
+        &mut self,
+        goblins: &HashMap<Player, Goblin>,
+        turn: &Turn,
+        phase: &AdventurePhase,
+    ) -> Option<GoblinLootInspectorEvent> {
         let mut event = None;
 
         set_camera(0, 0);
@@ -484,44 +790,482 @@ impl GoblinLootInspector {
         x = 66;
 
         let goblin = &goblins[&self.player];
-        let msg = &format!("LOOT BAG ({})", goblin.loot.len());
+        let msg = &format!("LOOT BAG ({}/{})", goblin.loot.len(), goblin.bag_size);
         text!(msg, x = x, y = y, color = WHITE);
+
+        // Pages
+        let num_pages = (goblin.bag_size as usize + Self::PAGE_LEN - 1) / Self::PAGE_LEN;
+        if num_pages > 1 {
+            let y = top + 1;
+            if cbutton(Font::S, 152, y, None, BLACK, WHITE, BLACK, "<") {
+                let _ = event.insert(GoblinLootInspectorEvent::PrevPage);
+            }
+            let msg = &format!("{}/{}", self.page + 1, num_pages);
+            text!(msg, x = 168, y = y + 4, font = Font::S, color = WHITE);
+            if cbutton(Font::S, 188, y, None, BLACK, WHITE, BLACK, ">") {
+                let _ = event.insert(GoblinLootInspectorEvent::NextPage);
+            }
+        }
+
+        // Items sit in their own slots, right of the title
+        let size = Self::SLOT_SIZE;
+        for i in 0..Goblin::MAX_ITEMS_LEN {
+            let x = x + (13 - Goblin::MAX_ITEMS_LEN + i) as i32 * size as i32;
+            let y = top + 1;
+            let border = if self.selected == Some(LootSlot::Item(i)) { WHITE } else { TRANSPARENT };
+            if cdiv(size - 1, size - 1, x, y, 0xffffff33, border) {
+                let _ = event.insert(GoblinLootInspectorEvent::SelectItem(i));
+            }
+            if let Some(item) = goblin.items.get(i) {
+                let msg = &item.name()[..1].to_ascii_uppercase();
+                text!(msg, x = x + 4, y = y + 3, color = WHITE);
+            }
+        }
         y += 10;
-        for i in 0..26 {
+
+        for i in 0..Self::PAGE_LEN {
             let cols = 13;
-            let w = 14;
-            let h = 14;
-            let x = x + (i % cols) * w as i32;
-            let y = y + (i / cols) * h as i32;
+            let x = x + (i % cols) as i32 * size as i32;
+            let y = y + (i / cols) as i32 * size as i32;
+            // Slots past the bag's size only open up with bag upgrades
+            let i = self.page * Self::PAGE_LEN + i;
+            if i >= goblin.bag_size as usize {
+                break;
+            }
             // rect!(w = w - 1, h = h - 1, x = x, y = y, fill = 0xffffff66);
-            if cdiv(w - 1, h - 1, x, y, 0xffffff33, TRANSPARENT) {
-                let _ = event.insert(GoblinLootInspectorEvent::SelectLoot(i as usize));
+            let border = if self.selected == Some(LootSlot::Loot(i)) { WHITE } else { TRANSPARENT };
+            if cdiv(size - 1, size - 1, x, y, 0xffffff33, border) {
+                let _ = event.insert(GoblinLootInspectorEvent::SelectLoot(i));
             }
-            if let Some(loot) = goblin.loot.get(i as usize) {
-                match loot.rarity {
-                    Rarity::Common => {
-                        sprite!("loot_1", x = x, y = y);
-                    }
-                    Rarity::Uncommon => {
-                        sprite!("loot_2", x = x, y = y);
-                    }
-                    Rarity::Rare => {
-                        sprite!("loot_3", x = x, y = y);
-                    }
-                    Rarity::Legendary => {
-                        sprite!("loot_4", x = x, y = y);
+            if let Some(loot) = goblin.loot.get(i) {
+                sprite!(loot.data().sprite, x = x, y = y);
+            }
+        }
+
+        // Details
+        let x = 66;
+        let mut y = top - Self::DETAILS_HEIGHT as i32 - 4;
+        let mut actions = vec![];
+        // Only the active goblin can rifle through their own bag
+        let can_act = self.player == turn.player;
+        match self.selected {
+            // A full bag takes priority over everything else
+            _ if !goblin.overflow.is_empty() => {
+                let data = goblin.overflow[0].data();
+                if cdiv(Self::DETAILS_WIDTH, Self::DETAILS_HEIGHT, x, y, BLACK, RED) {
+                    event = None;
+                }
+                let x = x + 4;
+                y += 4;
+                text!("BAG FULL!", x = x, y = y, color = RED);
+                y += 10;
+                let msg = &format!("FOUND: {}", data.name).to_ascii_uppercase();
+                text!(msg, x = x, y = y, font = Font::S, color = rarity_color(data.rarity));
+                y += 8;
+                let msg = &format!("VALUE: ${}  WEIGHT: {}", data.value, data.weight);
+                text!(msg, x = x, y = y, font = Font::S, color = WHITE);
+                y += 10;
+                let msg = "Pick a piece to swap out, or leave it for the heroes' sack.";
+                let msg = &insert_line_breaks(msg, Self::MAX_LINE_LEN);
+                text!(msg, x = x, y = y, color = 0xaaaaaaff);
+                if let Some(LootSlot::Loot(_)) = self.selected {
+                    actions.push((GoblinLootInspectorEvent::SwapOverflow, "SWAP".to_string()));
+                }
+                actions.push((GoblinLootInspectorEvent::LeaveOverflow, "LEAVE".to_string()));
+            }
+            Some(LootSlot::Loot(i)) if i < goblin.loot.len() => {
+                let data = goblin.loot[i].data();
+                // Clicking the details shouldn't close the inspector
+                if cdiv(Self::DETAILS_WIDTH, Self::DETAILS_HEIGHT, x, y, BLACK, WHITE) {
+                    event = None;
+                }
+                let x = x + 4;
+                y += 4;
+                text!(data.name, x = x, y = y, color = WHITE);
+                y += 10;
+                let msg = &format!("{:?}", data.rarity).to_ascii_uppercase();
+                text!(msg, x = x, y = y, font = Font::S, color = rarity_color(data.rarity));
+                y += 8;
+                let msg = &format!("VALUE: ${}  WEIGHT: {}", data.value, data.weight);
+                text!(msg, x = x, y = y, font = Font::S, color = WHITE);
+                y += 10;
+                let msg = &insert_line_breaks(data.flavor, Self::MAX_LINE_LEN);
+                text!(msg, x = x, y = y, color = 0xaaaaaaff);
+                if can_act {
+                    actions.push((GoblinLootInspectorEvent::Drop, "DROP".to_string()));
+                    // Picking a bribe replaces giving loot away
+                    if let Some(hero) = self.bribe_target {
+                        if let AdventurePhase::Camp(CampPhase { bribe_result: None, .. }) = phase {
+                            let msg = format!("BRIBE {:?}", hero).to_ascii_uppercase();
+                            actions.push((GoblinLootInspectorEvent::Bribe, msg));
+                        }
+                    } else {
+                        let mut players = goblins.keys().filter(|p| **p != self.player).collect::<Vec<_>>();
+                        players.sort();
+                        for player in players {
+                            actions.push((GoblinLootInspectorEvent::Give(*player), format!("{:?}", player)));
+                        }
                     }
-                    Rarity::Epic => {
-                        sprite!("loot_5", x = x, y = y);
+                }
+            }
+            Some(LootSlot::Item(i)) if i < goblin.items.len() => {
+                let item = goblin.items[i];
+                if cdiv(Self::DETAILS_WIDTH, Self::DETAILS_HEIGHT, x, y, BLACK, WHITE) {
+                    event = None;
+                }
+                let x = x + 4;
+                y += 4;
+                let msg = &item.name().replace('_', " ").to_ascii_uppercase();
+                text!(msg, x = x, y = y, color = WHITE);
+                y += 10;
+                text!("ITEM", x = x, y = y, font = Font::S, color = 0xaaaaaaff);
+                y += 18;
+                let msg = &insert_line_breaks(item.desc(), Self::MAX_LINE_LEN);
+                text!(msg, x = x, y = y, color = 0xaaaaaaff);
+                if can_act {
+                    if item.is_usable() {
+                        actions.push((GoblinLootInspectorEvent::Use, "USE".to_string()));
                     }
+                    actions.push((GoblinLootInspectorEvent::Drop, "DROP".to_string()));
+                }
+            }
+            _ => {}
+        }
+
+        // Detail actions
+        let mut x = x + 4;
+        let y = top - 4 - 17;
+        let mut is_give_labelled = false;
+        for (action, msg) in actions {
+            // The goblins loot can be given to share a single label
+            if let GoblinLootInspectorEvent::Give(_) = action {
+                if !is_give_labelled {
+                    text!("GIVE:", x = x, y = y + 4, font = Font::S, color = WHITE);
+                    x += 5 * 5 + 2;
+                    is_give_labelled = true;
                 }
             }
+            if cbutton(Font::S, x, y, None, BLACK, WHITE, BLACK, &msg) {
+                event = Some(action);
+            }
+            x += 5 * msg.len() as i32 + 8 + 4;
+        }
+
+        return event;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Dungeon Map
+////////////////////////////////////////////////////////////////////////////////
+
+impl DungeonMap {
+    pub const ROWS: usize = 8;
+    pub const ROW_HEIGHT: i32 = 26;
+    pub const COL_WIDTH: i32 = 80;
+    pub const MAX_NAME_LEN: usize = 14;
+    pub fn draw_button() -> Option<DungeonMapEvent> {
+        set_camera(0, 0);
+        if cbutton(Font::S, 4, 4, None, BLACK, WHITE, BLACK, "MAP") {
+            return Some(DungeonMapEvent::Open);
+        }
+        return None;
+    }
+    pub fn draw(&mut self, dungeon: &Dungeon) -> Option<DungeonMapEvent> {
+        let mut event = None;
+
+        set_camera(0, 0);
+        let [w, h] = resolution();
+        if cdiv(w, h, 0, 0, BACKDROP, BACKDROP) {
+            let _ = event.insert(DungeonMapEvent::Close);
+        }
+
+        // Title
+        let x = 8;
+        let y = 8;
+        text!("DUNGEON MAP", font = Font::L, x = x, y = y, color = WHITE);
+        let msg = &format!("DEPTH: {}", dungeon.depth());
+        text!(msg, font = Font::S, x = w as i32 - 8 - 5 * msg.len() as i32, y = y + 2, color = WHITE);
+
+        // Only the last few layers fit, so scroll with the frontier
+        let first_depth = (dungeon.max_depth() + 1).saturating_sub(Self::ROWS);
+        let position = |i: usize| {
+            let depth = dungeon.nodes[i].depth;
+            let layer = dungeon.layer(depth);
+            let col = layer.iter().position(|n| *n == i).unwrap_or(0) as i32;
+            let cols = layer.len() as i32;
+            let x = w as i32 / 2 + (2 * col - (cols - 1)) * Self::COL_WIDTH / 2;
+            let y = 32 + (depth - first_depth) as i32 * Self::ROW_HEIGHT;
+            (x, y)
+        };
+        let visible = |i: usize| dungeon.nodes[i].depth >= first_depth;
+        let is_visited = |i: usize| dungeon.route.contains(&i);
+        let paths = dungeon.paths();
+        // Unvisited nodes are only revealed once a path to them is known
+        let is_known = |i: usize| {
+            is_visited(i) || paths.contains(&i) || dungeon.route.iter().any(|r| dungeon.nodes[*r].edges.contains(&i))
+        };
+
+        // Paths
+        for (i, node) in dungeon.nodes.iter().enumerate() {
+            if !visible(i) || !is_visited(i) {
+                continue;
+            }
+            let (x0, y0) = position(i);
+            for e in &node.edges {
+                let (x1, y1) = position(*e);
+                let is_taken = dungeon.route.windows(2).any(|r| r[0] == i && r[1] == *e);
+                dotted_line(x0, y0, x1, y1, if is_taken { WHITE } else { 0xffffff55 });
+            }
         }
 
+        // Locations
+        let content = content();
+        for (i, node) in dungeon.nodes.iter().enumerate() {
+            if !visible(i) || !is_known(i) {
+                continue;
+            }
+            let (x, y) = position(i);
+            let is_current = dungeon.current() == Some(i);
+            let fill = if is_current {
+                GREEN
+            } else if is_visited(i) {
+                WHITE
+            } else {
+                0x777777ff
+            };
+            circ!(d = 8, x = x - 4, y = y - 4, fill = fill);
+            let name = &content.location(node.location).name;
+            let name = name.chars().take(Self::MAX_NAME_LEN).collect::<String>();
+            let tx = x - 5 * name.len() as i32 / 2;
+            text!(&name, font = Font::S, x = tx, y = y + 6, color = fill);
+        }
+
+        let msg = "CLICK ANYWHERE TO CLOSE";
+        text!(msg, font = Font::S, x = 8, y = h as i32 - 12, color = WHITE);
+
         return event;
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////
+// Toasts
+////////////////////////////////////////////////////////////////////////////////
+
+impl Toast {
+    // How many frames a toast stays up
+    pub const DURATION: u32 = 180;
+    pub const MAX_VISIBLE: usize = 3;
+}
+
+impl GUI {
+    /// Draws the oldest toasts over everything else and counts them down.
+    pub fn draw_toasts(&mut self) {
+        set_camera(0, 0);
+        let [sw, _sh] = resolution();
+        let mut y = 4;
+        for toast in self.toasts.iter_mut().take(Toast::MAX_VISIBLE) {
+            let w = 5 * toast.msg.len() as u32 + 12;
+            let x = sw as i32 - w as i32 - 4;
+            cdiv(w, 16, x, y, BLACK, YELLOW);
+            text!(&toast.msg, x = x + 6, y = y + 4, color = YELLOW);
+            toast.timer = toast.timer.saturating_sub(1);
+            y += 18;
+        }
+        self.toasts.retain(|toast| toast.timer > 0);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Profile
+////////////////////////////////////////////////////////////////////////////////
+
+/// Draws the career stats of one seat played from this wallet. Returns whether
+/// the player wants to go back.
+pub fn draw_profile(profile: Option<&Profile>, owner: Pubkey, player: &mut Player) -> bool {
+    sprite!("parchment_bg");
+    let [sw, sh] = resolution();
+    let (x, y) = (8, 4);
+    rect!(w = sw, h = 16, fill = BG);
+    text!("PROFILE", font = Font::L, x = x, y = y, color = FG);
+    let y = y + 24;
+
+    // Everyone who plays from this wallet keeps the profile of their seat
+    let players = [Player::P1, Player::P2, Player::P3, Player::P4];
+    if let Some(step) = stepper(x, y, "PLAYER", &format!("{:?}", player)) {
+        let i = (player.index() as i32 + step as i32 + 4) % 4;
+        *player = players[i as usize];
+    }
+    let mut y = y + 20;
+
+    let new_profile;
+    let profile = match profile {
+        Some(profile) => profile,
+        None => {
+            new_profile = Profile::new(owner);
+            &new_profile
+        }
+    };
+
+    // Career
+    text!("Career", x = x, y = y, color = FG);
+    let stats = [
+        ("Adventures", profile.adventures_played),
+        ("Wins", profile.wins),
+        ("Times caught", profile.times_caught),
+        ("Slap fights won", profile.slap_fights_won),
+    ];
+    for (i, (key, val)) in stats.into_iter().enumerate() {
+        let y = y + 12 + i as i32 * 8;
+        text!(&format!("{:<16}{}", key, val), x = x, y = y, font = Font::S, color = FG);
+    }
+
+    // Loot
+    let x = sw as i32 / 2;
+    text!(&format!("Loot ({})", profile.total_loot()), x = x, y = y, color = FG);
+    for (i, (rarity, count)) in Rarity::ALL.iter().zip(profile.loot).enumerate() {
+        let y = y + 12 + i as i32 * 8;
+        let msg = &format!("{:<16}{}", format!("{:?}", rarity), count);
+        text!(msg, x = x, y = y, font = Font::S, color = FG);
+    }
+    let x = 8;
+    y += 12 + Rarity::ALL.len() as i32 * 8 + 4;
+
+    // Achievements
+    text!("Achievements", x = x, y = y, color = FG);
+    y += 12;
+    for achievement in Achievement::ALL {
+        let is_unlocked = profile.achievements.contains(achievement);
+        let msg = &if is_unlocked {
+            format!("{} - {}", achievement.name(), achievement.desc())
+        } else {
+            format!("??? - {}", achievement.desc())
+        };
+        text!(msg, x = x, y = y, font = Font::S, color = FG);
+        y += 8;
+    }
+    y += 4;
+
+    // Cosmetics
+    text!("Cosmetics", x = x, y = y, color = FG);
+    y += 12;
+    for cosmetic in Cosmetic::ALL {
+        let is_unlocked = profile.cosmetics.contains(cosmetic);
+        let msg = &if is_unlocked {
+            cosmetic.name().to_string()
+        } else {
+            format!("??? - {}", cosmetic.desc())
+        };
+        text!(msg, x = x, y = y, font = Font::S, color = FG);
+        y += 8;
+    }
+
+    // Back
+    let sh = sh as i32;
+    let x = 4 + 4;
+    let y = sh - 32;
+    return button(Font::L, x, y, "    BACK    ");
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Leaderboard
+////////////////////////////////////////////////////////////////////////////////
+
+/// Draws one board of the leaderboard with controls to switch boards. `day`
+/// picks the daily board. Returns whether the player wants to go back.
+pub fn draw_leaderboard(
+    leaderboard: &Leaderboard,
+    day: u32,
+    party_size: &mut u8,
+    num_rounds: &mut u8,
+    is_daily: &mut bool,
+) -> bool {
+    sprite!("parchment_bg");
+    let [sw, sh] = resolution();
+    let (x, y) = (8, 4);
+    rect!(w = sw, h = 16, fill = BG);
+    text!("LEADERBOARD", font = Font::L, x = x, y = y, color = FG);
+    let msg = if *is_daily { "  DAILY " } else { "ALL TIME" };
+    if button(Font::M, sw as i32 - 60, y - 2, msg) {
+        *is_daily = !*is_daily;
+    }
+    let y = y + 24;
+
+    // Today's challenge has fixed settings, so it only has the one board
+    let board = if *is_daily {
+        let msg = &format!("Challenge #{}", day);
+        text!(msg, x = x, y = y, color = FG);
+        leaderboard.daily_board(day)
+    } else {
+        draw_leaderboard_filters(leaderboard, party_size, num_rounds, x, y);
+        leaderboard.board(*party_size, *num_rounds)
+    };
+    let y = y + 20;
+
+    // Scores
+    if board.is_empty() {
+        text!("No scores yet. Go steal something!", x = x, y = y, font = Font::S, color = FG);
+    }
+    for (i, entry) in board.iter().enumerate() {
+        let y = y + i as i32 * 16;
+        div(sw - 16, 15, x, y);
+        let msg = &format!("#{:<2} {:?} {}", i + 1, entry.player, entry.name);
+        text!(msg, x = x + 4, y = y + 4, color = FG);
+        // Scores that share a hash come from the same run, and ones that don't
+        // match their checksum were edited after the fact
+        let msg = &format!("{:0>16x}", entry.replay_hash);
+        #[rustfmt::skip]
+        text!(msg, x = x + 108, y = y + 5, font = Font::S, color = if entry.is_valid() { FG } else { RED });
+        let msg = &format!("${:0>3}", entry.score);
+        text!(msg, x = sw as i32 - 48, y = y + 4, color = FG);
+    }
+    if board.iter().any(|entry| !entry.is_valid()) {
+        let msg = "Scores with a red hash were tampered with.";
+        text!(msg, x = x, y = sh as i32 - 44, font = Font::S, color = RED);
+    }
+
+    // Back
+    let sh = sh as i32;
+    let x = 4 + 4;
+    let y = sh - 32;
+    return button(Font::L, x, y, "    BACK    ");
+}
+
+/// Draws the party size and round count pickers of the all time boards.
+fn draw_leaderboard_filters(
+    leaderboard: &Leaderboard,
+    party_size: &mut u8,
+    num_rounds: &mut u8,
+    x: i32,
+    y: i32,
+) {
+    // Party size
+    text!("Goblins", x = x, y = y, color = FG);
+    if ibutton(Font::M, x + 64, y - 4, "<") {
+        *party_size = (*party_size + 2) % 4 + 1;
+    }
+    text!(&format!("{}", party_size), x = x + 84, y = y, color = FG);
+    if ibutton(Font::M, x + 96, y - 4, ">") {
+        *party_size = *party_size % 4 + 1;
+    }
+
+    // Rounds, cycling through the boards that exist
+    let round_counts = leaderboard.round_counts(*party_size);
+    if !round_counts.is_empty() && !round_counts.contains(num_rounds) {
+        *num_rounds = round_counts[0];
+    }
+    let x = 128;
+    text!("Rounds", x = x, y = y, color = FG);
+    let i = round_counts.iter().position(|n| n == num_rounds).unwrap_or(0);
+    if ibutton(Font::M, x + 56, y - 4, "<") && !round_counts.is_empty() {
+        *num_rounds = round_counts[(i + round_counts.len() - 1) % round_counts.len()];
+    }
+    text!(&format!("{:0>3}", num_rounds), x = x + 76, y = y, color = FG);
+    if ibutton(Font::M, x + 96, y - 4, ">") && !round_counts.is_empty() {
+        *num_rounds = round_counts[(i + 1) % round_counts.len()];
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Utils
 ////////////////////////////////////////////////////////////////////////////////
@@ -659,6 +1403,52 @@ pub fn idiv(w: u32, h: u32, x: i32, y: i32) -> bool {
     did_intersect && m.left.just_pressed()
 }
 
+/// Draws a labelled value with buttons to step it down or up. Returns the
+/// step that was clicked.
+pub fn stepper(x: i32, y: i32, label: &str, value: &str) -> Option<i8> {
+    text!(label, x = x, y = y + 5, font = Font::S, color = FG);
+    let mut step = None;
+    if ibutton(Font::M, x + 40, y, "<") {
+        step = Some(-1);
+    }
+    text!(value, x = x + 60, y = y + 4, color = FG);
+    if ibutton(Font::M, x + 104, y, ">") {
+        step = Some(1);
+    }
+    step
+}
+
+pub fn rarity_color(rarity: Rarity) -> u32 {
+    match rarity {
+        Rarity::Common => WHITE,
+        Rarity::Uncommon => GREEN,
+        Rarity::Rare => 0x3399ffff,
+        Rarity::Legendary => MAGENTA,
+        Rarity::Epic => YELLOW,
+    }
+}
+
+/// Draws a 24x24 hero portrait from simple shapes.
+pub fn draw_hero_portrait(hero: HeroKind, x: i32, y: i32) {
+    let p = hero.data().portrait;
+    rect!(w = 24, h = 24, x = x, y = y, fill = p.accent);
+    // Face
+    circ!(d = 16, x = x + 4, y = y + 6, fill = p.skin);
+    rect!(w = 16, h = 3, x = x + 4, y = y + 5, fill = p.hair);
+    // Hat
+    rect!(w = 18, h = 4, x = x + 3, y = y + 3, fill = p.hat);
+    rect!(w = 10, h = 4, x = x + 7, y = y, fill = p.hat);
+    if p.has_mask {
+        rect!(w = 16, h = 6, x = x + 4, y = y + 14, fill = p.hat);
+    }
+    if p.has_beard {
+        rect!(w = 12, h = 5, x = x + 6, y = y + 18, fill = p.hair);
+    }
+    // Eyes
+    rect!(w = 2, h = 2, x = x + 8, y = y + 11, fill = BLACK);
+    rect!(w = 2, h = 2, x = x + 14, y = y + 11, fill = BLACK);
+}
+
 pub fn draw_cursor() {
     set_camera(0, 0);
     let m = mouse(0);
@@ -670,6 +1460,37 @@ pub fn draw_cursor() {
     }
 }
 
+#[cfg(feature = "hot-reload")]
+pub fn draw_content_reload_error() {
+    if let Some(err) = content_reload_error() {
+        set_camera(0, 0);
+        let [w, _h] = resolution();
+        let msg = insert_line_breaks(&err, 48);
+        let h = 14 + 8 * msg.lines().count() as u32;
+        rect!(w = w, h = h, fill = RED);
+        text!("CONTENT ERROR", x = 4, y = 4, font = Font::S, color = WHITE);
+        text!(&msg, x = 4, y = 12, font = Font::S, color = WHITE);
+    }
+}
+
+fn dotted_line(x0: i32, y0: i32, x1: i32, y1: i32, color: u32) {
+    let steps = (x1 - x0).abs().max((y1 - y0).abs()) / 3;
+    for i in 0..=steps {
+        let x = x0 + (x1 - x0) * i / steps.max(1);
+        let y = y0 + (y1 - y0) * i / steps.max(1);
+        rect!(w = 1, h = 1, x = x, y = y, fill = color);
+    }
+}
+
+/// The first `len` characters of a message, for typing it out one character
+/// at a time. Dialog can come from content files, so it isn't always ASCII.
+fn reveal(message: &str, len: usize) -> &str {
+    match message.char_indices().nth(len) {
+        Some((end, _)) => &message[..end],
+        None => message,
+    }
+}
+
 fn insert_line_breaks(input: &str, max_line_length: usize) -> String {
     let mut result = String::new();
     let mut current_line_length = 0;
@@ -695,3 +1516,22 @@ fn insert_line_breaks(input: &str, max_line_length: usize) -> String {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveal_by_characters() {
+        let message = "Ooh, a crème brûlée!";
+        let revealed = (0..=message.chars().count() + 1)
+            .map(|len| reveal(message, len))
+            .collect::<Vec<_>>();
+        assert_eq!(revealed[0], "");
+        assert_eq!(revealed[8], "Ooh, a c");
+        assert_eq!(revealed[9], "Ooh, a cr");
+        assert_eq!(revealed[10], "Ooh, a crè");
+        assert_eq!(revealed[20], message);
+        assert_eq!(revealed[21], message);
+    }
+}